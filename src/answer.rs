@@ -0,0 +1,78 @@
+use std::fmt;
+
+/// Answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Wide enough for any answer of the integer types it is made from.
+    Integer(i128),
+    Text(String),
+    /// Values answered as a comma separated list, such as program output or sorted names.
+    List(Vec<String>),
+    /// Part that has no solver (yet).
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::List(values) => write!(f, "{}", values.join(",")),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        // Beyond an i128 only the digits can be kept
+        match i128::try_from(value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(values: Vec<String>) -> Self {
+        Answer::List(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_integers() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(-3_i32), Answer::Integer(-3));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::input::data_root;
+use crate::Answer;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Result of checking an answer against the known answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    /// No known answer for this part yet.
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+/// Known answers, keyed by day and part.
+///
+/// Stored as a small subset of TOML, with a table per day:
+///
+/// ```text
+/// [day01]
+/// part1 = 11
+/// part2 = 31
+///
+/// [day17]
+/// part1 = "4,6,3,5,6,3,5,2,1,0"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        let mut day = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let error = |message: &str| format!("line {}: {}", i + 1, message);
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // Table header `[dayNN]`
+            if let Some(header) = line.strip_prefix('[') {
+                let number = header.strip_suffix(']')
+                    .and_then(|header| header.strip_prefix("day"))
+                    .and_then(|number| number.parse::<u8>().ok())
+                    .ok_or_else(|| error("expected a `[dayNN]` table"))?;
+                day = Some(number);
+                continue;
+            }
+
+            // `partN = value`, with strings in quotes
+            let day = day.ok_or_else(|| error("expected a `[dayNN]` table before answers"))?;
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| error("expected `partN = answer`"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(error("expected `part1` or `part2`")),
+            };
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted.strip_suffix('"')
+                    .ok_or_else(|| error("unterminated string"))?,
+                None if value.parse::<i64>().is_ok() => value,
+                None => return Err(error("expected a number or a quoted string")),
+            };
+
+            answers.insert((day, part), value.to_string());
+        }
+
+        Ok(Answers { answers })
+    }
+
+    /// Read the answers from a file, or `None` if there is no such file.
+    pub fn load(path: &Path) -> Result<Option<Self>, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Some(Answers::parse(&text)
+                .map_err(|e| format!("{}: {}", path.display(), e))?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Known answer for a part, as it is displayed.
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    /// Check an answer, or `None` for an unsolved part with no known answer.
+    /// Leaving a part with a known answer unsolved fails.
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Option<Verdict> {
+        Some(match self.get(day, part) {
+            Some(expected) if *answer == Answer::Unsolved => Verdict::Fail { expected: expected.to_string() },
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() },
+            None if *answer == Answer::Unsolved => return None,
+            None => Verdict::New,
+        })
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: &Answer) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    /// Answers in the same format that is parsed (comments are not kept).
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut last_day = None;
+        for (&(day, part), answer) in self.answers.iter() {
+            if last_day != Some(day) {
+                if last_day.is_some() {
+                    toml.push('\n');
+                }
+                writeln!(toml, "[day{:02}]", day).unwrap();
+                last_day = Some(day);
+            }
+
+            if answer.parse::<i64>().is_ok() {
+                writeln!(toml, "part{} = {}", part, answer).unwrap();
+            } else {
+                writeln!(toml, "part{} = \"{}\"", part, answer).unwrap();
+            }
+        }
+
+        toml
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+}
+
+/// Default location of the answers file, `answers.toml` in the data root.
+pub fn answers_path() -> PathBuf {
+    data_root().join("answers.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tables() {
+        let answers = Answers::parse("\
+# Comment
+[day01]
+part1 = 11
+part2 = 31
+
+[day17]
+part1 = \"4,6,3,5,6,3,5,2,1,0\"
+").unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("11"));
+        assert_eq!(answers.get(1, 2), Some("31"));
+        assert_eq!(answers.get(17, 1), Some("4,6,3,5,6,3,5,2,1,0"));
+        assert_eq!(answers.get(17, 2), None);
+    }
+
+    #[test]
+    fn check_answers() {
+        let mut answers = Answers::parse("[day01]\npart1 = 11").unwrap();
+
+        assert_eq!(answers.check(1, 1, &Answer::Integer(11)), Some(Verdict::Pass));
+        assert_eq!(answers.check(1, 1, &Answer::Integer(12)), Some(Verdict::Fail { expected: "11".to_string() }));
+        assert_eq!(answers.check(1, 2, &Answer::Integer(31)), Some(Verdict::New));
+        assert_eq!(answers.check(25, 2, &Answer::Unsolved), None);
+        assert_eq!(answers.check(1, 1, &Answer::Unsolved), Some(Verdict::Fail { expected: "11".to_string() }));
+
+        answers.insert(1, 2, &Answer::Integer(31));
+        answers.insert(23, 2, &Answer::List(vec!["co".to_string(), "de".to_string()]));
+        assert_eq!(answers.to_toml(), "[day01]\npart1 = 11\npart2 = 31\n\n[day23]\npart2 = \"co,de\"\n");
+        assert_eq!(Answers::parse(&answers.to_toml()), Ok(answers));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Answers::parse("part1 = 1"),
+                   Err("line 1: expected a `[dayNN]` table before answers".to_string()));
+        assert_eq!(Answers::parse("[day01]\npart3 = 1"),
+                   Err("line 2: expected `part1` or `part2`".to_string()));
+        assert_eq!(Answers::parse("[day01]\npart1 = \"abc"),
+                   Err("line 2: unterminated string".to_string()));
+    }
+}
@@ -0,0 +1,209 @@
+use crate::runner::{solve_day, Parts};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// Relative growth of the median time that is flagged as a regression.
+pub const REGRESSION_THRESHOLD: f64 = 0.1;
+
+/// A timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Step {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::Part1 => "part1",
+            Step::Part2 => "part2",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Step::Parse),
+            "part1" => Some(Step::Part1),
+            "part2" => Some(Step::Part2),
+            _ => None,
+        }
+    }
+}
+
+/// Minimum, median and maximum of repeated timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Statistics of a set of timings, `None` if there are none.
+    pub fn from_times(times: &[Duration]) -> Option<Self> {
+        let mut sorted = times.to_vec();
+        sorted.sort();
+
+        Some(Stats {
+            min: *sorted.first()?,
+            median: sorted[sorted.len() / 2],
+            max: *sorted.last()?,
+        })
+    }
+
+    /// Relative change of the median from a previous run (0.1 is 10% slower).
+    pub fn change_from(&self, previous: &Stats) -> f64 {
+        let previous = previous.median.as_secs_f64();
+        if previous == 0.0 {
+            return 0.0;
+        }
+
+        (self.median.as_secs_f64() - previous) / previous
+    }
+
+    pub fn is_regression_from(&self, previous: &Stats) -> bool {
+        self.change_from(previous) > REGRESSION_THRESHOLD
+    }
+}
+
+/// Benchmark results keyed by day and step.
+///
+/// Saved as one line per step, with times in nanoseconds:
+///
+/// ```text
+/// # day step min median max
+/// 6 part2 1021322 1034011 1102457
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Benchmarks {
+    results: BTreeMap<(u8, Step), Stats>,
+}
+
+impl Benchmarks {
+    pub fn insert(&mut self, day: u8, step: Step, stats: Stats) {
+        self.results.insert((day, step), stats);
+    }
+
+    pub fn get(&self, day: u8, step: Step) -> Option<&Stats> {
+        self.results.get(&(day, step))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(u8, Step, &Stats)> {
+        self.results.iter().map(|(&(day, step), stats)| (day, step, stats))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut benchmarks = Benchmarks::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = || format!("line {}: expected `day step min median max`", i + 1);
+            let fields = line.split_ascii_whitespace().collect::<Vec<_>>();
+            let [day, step, min, median, max] = fields[..] else {
+                return Err(error());
+            };
+            let nanos = |s: &str| s.parse::<u64>().map(Duration::from_nanos).map_err(|_| error());
+
+            benchmarks.insert(day.parse().map_err(|_| error())?,
+                              Step::from_name(step).ok_or_else(error)?,
+                              Stats { min: nanos(min)?, median: nanos(median)?, max: nanos(max)? });
+        }
+
+        Ok(benchmarks)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# day step min median max\n");
+        for (day, step, stats) in self.iter() {
+            writeln!(text, "{} {} {} {} {}",
+                     day, step.name(), stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos()).unwrap();
+        }
+
+        text
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read benchmarks {}: {}", path.display(), e))?;
+
+        Ok(Benchmarks::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+}
+
+
+/// Time parsing and the selected parts of a day over a number of repetitions.
+pub fn bench_day(day: u8,
+                 input: &str,
+                 parts: Parts,
+                 repetitions: usize) -> Result<Vec<(Step, Stats)>, Box<dyn Error>> {
+    let mut times: BTreeMap<Step, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..repetitions {
+        let report = solve_day(day, input, parts)?;
+
+        times.entry(Step::Parse).or_default().push(report.parse_time);
+        if let Some(part1) = report.part1 {
+            times.entry(Step::Part1).or_default().push(part1.time);
+        }
+        if let Some(part2) = report.part2 {
+            times.entry(Step::Part2).or_default().push(part2.time);
+        }
+    }
+
+    Ok(times.into_iter()
+        .filter_map(|(step, times)| Stats::from_times(&times).map(|stats| (step, stats)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(min: u64, median: u64, max: u64) -> Stats {
+        Stats {
+            min: Duration::from_nanos(min),
+            median: Duration::from_nanos(median),
+            max: Duration::from_nanos(max),
+        }
+    }
+
+    #[test]
+    fn stats_from_times() {
+        let times = [5, 1, 4, 2, 3].map(Duration::from_nanos);
+        assert_eq!(Stats::from_times(&times), Some(stats(1, 3, 5)));
+        assert_eq!(Stats::from_times(&[]), None);
+    }
+
+    #[test]
+    fn regressions() {
+        let previous = stats(90, 100, 120);
+        assert!(stats(100, 120, 130).is_regression_from(&previous));
+        assert!(!stats(100, 105, 130).is_regression_from(&previous));
+        assert!(!stats(40, 50, 60).is_regression_from(&previous));
+    }
+
+    #[test]
+    fn save_and_parse() {
+        let mut benchmarks = Benchmarks::default();
+        benchmarks.insert(6, Step::Part2, stats(1000, 1200, 1500));
+        benchmarks.insert(6, Step::Parse, stats(10, 11, 12));
+
+        let text = benchmarks.to_text();
+        assert_eq!(text, "# day step min median max\n6 parse 10 11 12\n6 part2 1000 1200 1500\n");
+        assert_eq!(Benchmarks::parse(&text), Ok(benchmarks));
+        assert!(Benchmarks::parse("6 part3 1 2 3").is_err());
+    }
+}
@@ -0,0 +1,307 @@
+use aoc24::bench::{bench_day, Benchmarks};
+use aoc24::input::{data_root, input_path_in, read_input, InputSource};
+use aoc24::runner::{format_duration, solve_day, DayReport, PartReport, Parts, DAYS};
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, process};
+
+const USAGE: &str = "\
+Usage: aoc [DAYS...] [--part 1|2] [--data DIR] [--input FILE]
+       aoc [DAYS...] --bench N [--save FILE] [--compare FILE] [...]
+
+Runs the selected days and prints a table of answers and timings.
+
+DAYS is a day number (6), an inclusive range (3-7) or `all` (the default).
+
+Options:
+  -p, --part 1|2       Only solve one part
+  -d, --data DIR       Root of the puzzle inputs (default: $AOC24_DATA or `data`)
+  -i, --input FILE     Input file for a single day, `-` to read stdin
+
+Benchmarking:
+  -b, --bench N        Time parsing and each part over N runs, showing min/median/max
+  -s, --save FILE      Save the benchmark results
+      --compare FILE   Compare with saved results, flagging regressions of the median";
+
+
+struct Options {
+    days: Vec<u8>,
+    parts: Parts,
+    data: PathBuf,
+    input: Option<InputSource>,
+    bench: Option<usize>,
+    save: Option<PathBuf>,
+    compare: Option<PathBuf>,
+}
+
+fn parse_days(arg: &str) -> Result<Vec<u8>, String> {
+    if arg == "all" {
+        return Ok(DAYS.to_vec());
+    }
+
+    let (first, last) = match arg.split_once('-') {
+        Some((first, last)) => (first, last),
+        None => (arg, arg),
+    };
+    let first = first.parse::<u8>().map_err(|_| format!("Invalid day: {}", arg))?;
+    let last = last.parse::<u8>().map_err(|_| format!("Invalid day: {}", arg))?;
+    if first > last {
+        return Err(format!("Invalid day range: {}", arg));
+    }
+
+    // Only keep days that have a solver, unless a single day was asked for
+    let days = (first..=last).collect::<Vec<_>>();
+    if days.len() == 1 {
+        Ok(days)
+    } else {
+        Ok(days.into_iter().filter(|day| DAYS.contains(day)).collect())
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut days = Vec::new();
+    let mut parts = Parts::Both;
+    let mut data = data_root();
+    let mut input = None;
+    let mut bench = None;
+    let mut save = None;
+    let mut compare = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => {
+                parts = match args.next().map(|s| s.as_str()) {
+                    Some("1") => Parts::Part1,
+                    Some("2") => Parts::Part2,
+                    _ => return Err("--part must be 1 or 2".to_string()),
+                };
+            }
+            "-d" | "--data" => {
+                data = args.next().map(PathBuf::from)
+                    .ok_or("--data needs a directory")?;
+            }
+            "-i" | "--input" => {
+                let arg = args.next().ok_or("--input needs a file")?;
+                input = Some(InputSource::from_arg(Some(arg)));
+            }
+            "-b" | "--bench" => {
+                bench = match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(repetitions)) if repetitions > 0 => Some(repetitions),
+                    _ => return Err("--bench needs a number of runs".to_string()),
+                };
+            }
+            "-s" | "--save" => {
+                save = Some(args.next().map(PathBuf::from)
+                    .ok_or("--save needs a file")?);
+            }
+            "--compare" => {
+                compare = Some(args.next().map(PathBuf::from)
+                    .ok_or("--compare needs a file")?);
+            }
+            "-h" | "--help" => return Err(String::new()),
+            _ => days.extend(parse_days(arg)?),
+        }
+    }
+
+    if days.is_empty() {
+        days = DAYS.to_vec();
+    }
+    days.sort();
+    days.dedup();
+
+    if input.is_some() && days.len() != 1 {
+        return Err("--input needs exactly one day".to_string());
+    }
+    if bench.is_none() && (save.is_some() || compare.is_some()) {
+        return Err("--save and --compare need --bench".to_string());
+    }
+
+    Ok(Options { days, parts, data, input, bench, save, compare })
+}
+
+
+fn read_day(day: u8, options: &Options) -> Result<String, Box<dyn Error>> {
+    if !DAYS.contains(&day) {
+        return Err(format!("No solver for day {}", day).into());
+    }
+
+    let source = options.input.clone()
+        .unwrap_or_else(|| InputSource::Path(input_path_in(&options.data, day)));
+
+    Ok(read_input(day, &source)?)
+}
+
+fn run_day(day: u8, options: &Options) -> Result<DayReport, Box<dyn Error>> {
+    solve_day(day, &read_day(day, options)?, options.parts)
+}
+
+fn answer(part: &Option<PartReport>) -> String {
+    part.as_ref().map_or(String::new(), |p| p.answer.to_string())
+}
+
+fn time(part: &Option<PartReport>) -> String {
+    part.as_ref().map_or(String::new(), |p| format_duration(p.time))
+}
+
+/// Print a table, with the first `left` columns left-aligned and the rest right-aligned.
+fn print_table<const N: usize>(header: [&str; N], left: usize, rows: &[[String; N]]) {
+    // Width of each column
+    let mut widths = header.map(|h| h.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &[&str]| {
+        let cells = row.iter().zip(widths.iter())
+            .enumerate()
+            .map(|(i, (cell, width))| if i < left {
+                format!("{:<width$}", cell, width = width)
+            } else {
+                format!("{:>width$}", cell, width = width)
+            })
+            .collect::<Vec<_>>();
+        println!("{}", cells.join(" | ").trim_end());
+    };
+
+    print_row(&header);
+    println!("{}", widths.iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("-+-"));
+    for row in rows {
+        print_row(&row.each_ref().map(|cell| cell.as_str()));
+    }
+}
+
+
+fn solve(options: &Options) -> bool {
+    let mut rows = Vec::new();
+    let mut total = Duration::ZERO;
+    let mut failed = false;
+    for &day in options.days.iter() {
+        match run_day(day, options) {
+            Ok(report) => {
+                total += report.total_time();
+
+                rows.push([
+                    format!("{:2}", day),
+                    answer(&report.part1),
+                    answer(&report.part2),
+                    format_duration(report.parse_time),
+                    time(&report.part1),
+                    time(&report.part2),
+                ]);
+            }
+            Err(e) => {
+                failed = true;
+                rows.push([
+                    format!("{:2}", day),
+                    format!("error: {}", e),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ]);
+            }
+        }
+    }
+
+    print_table(["Day", "Part 1", "Part 2", "Parse", "Time 1", "Time 2"], 3, &rows);
+    println!("\nTotal time: {}", format_duration(total));
+
+    !failed
+}
+
+fn bench(options: &Options, repetitions: usize) -> Result<bool, Box<dyn Error>> {
+    let previous = match &options.compare {
+        Some(path) => Some(Benchmarks::load(path)?),
+        None => None,
+    };
+
+    let mut benchmarks = Benchmarks::default();
+    let mut rows = Vec::new();
+    let mut failed = false;
+    let mut regressions = 0;
+    for &day in options.days.iter() {
+        let steps = match read_day(day, options).and_then(|input| bench_day(day, &input, options.parts, repetitions)) {
+            Ok(steps) => steps,
+            Err(e) => {
+                failed = true;
+                eprintln!("Day {}: error: {}", day, e);
+                continue;
+            }
+        };
+
+        for (step, stats) in steps {
+            benchmarks.insert(day, step, stats);
+
+            // Change of the median from the previous run
+            let previous_stats = previous.as_ref().and_then(|previous| previous.get(day, step));
+            let (previous_median, change) = match previous_stats {
+                Some(previous_stats) => {
+                    let regression = stats.is_regression_from(previous_stats);
+                    regressions += regression as usize;
+                    (format_duration(previous_stats.median),
+                     format!("{:+.1}%{}", 100.0 * stats.change_from(previous_stats),
+                             if regression { " REGRESSION" } else { "" }))
+                }
+                None => (String::new(), String::new()),
+            };
+
+            rows.push([
+                format!("{:2}", day),
+                step.name().to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+                previous_median,
+                change,
+            ]);
+        }
+    }
+
+    println!("{} runs of each day\n", repetitions);
+    print_table(["Day", "Step", "Min", "Median", "Max", "Previous", "Change"], 2, &rows);
+
+    if regressions > 0 {
+        println!("\n{} regression(s) in the median time", regressions);
+    }
+    if let Some(path) = &options.save {
+        benchmarks.save(path)
+            .map_err(|e| format!("cannot save benchmarks to {}: {}", path.display(), e))?;
+        println!("\nSaved to {}", path.display());
+    }
+
+    Ok(!failed && regressions == 0)
+}
+
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            if !message.is_empty() {
+                eprintln!("{}\n", message);
+            }
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    let passed = match options.bench {
+        Some(repetitions) => bench(&options, repetitions).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            false
+        }),
+        None => solve(&options),
+    };
+
+    if !passed {
+        process::exit(1);
+    }
+}
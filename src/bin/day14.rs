@@ -1,99 +1,68 @@
-use std::collections::HashSet;
+use aoc24::days::day14::{tree_frames, Day14};
+use aoc24::input::read_input;
+use aoc24::render;
+use aoc24::runner::{run_on, DayArgs, DAY_USAGE};
+use aoc24::Solution;
+use std::env;
 use std::error::Error;
-use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
 
-#[derive(Debug, Clone)]
-struct State {
-    pos: [i64; 2],
-    vel: [i64; 2],
-}
-
-impl State {
-    fn predict_position(&self, time: i64, x_width: i64, y_width: i64) -> [i64; 2] {
-        let new_x = i64_mod(self.pos[0] + time * self.vel[0], x_width);
-        let new_y = i64_mod(self.pos[1] + time * self.vel[1], y_width);
+const TREE_USAGE: &str = "
+      --around-tree    Only record the steps either side of the tree, with --frames";
 
-        [new_x, new_y]
-    }
-}
+/// Record the steps around the tree instead of the whole simulation.
+fn record_tree(file_str: &str, path: &Path, every: usize) -> Result<(), Box<dyn Error>> {
+    let robots = Day14::parse(file_str)?;
+    let count = render::record(tree_frames(&robots, every), path)
+        .map_err(|e| format!("cannot write frames to {}: {}", path.display(), e))?;
+    println!("Recorded {} frames to {}", count, path.display());
 
-fn i64_mod(x: i64, v: i64) -> i64 {
-    ((x % v) + v) % v
+    Ok(())
 }
 
-fn part1(robots: &Vec<State>, steps: i64, x_width: i64, y_width: i64) {
-    let mut new_locations = Vec::new();
-    for robot in robots {
-        let new_pos = robot.predict_position(steps, x_width, y_width);
-        new_locations.push(new_pos);
+fn main() -> ExitCode {
+    let usage = format!("{}{}", DAY_USAGE, TREE_USAGE);
+    let mut env_args = env::args().skip(1).collect::<Vec<_>>();
+    let around_tree = env_args.iter().any(|arg| arg == "--around-tree");
+    env_args.retain(|arg| arg != "--around-tree");
+    let mut args = DayArgs::from_args(&env_args, &usage);
+    if let Some(arg) = args.extra.first() {
+        eprintln!("Unexpected argument: {}\n\n{}", arg, usage);
+        return ExitCode::from(2);
     }
 
-    let mut counts = [0_u64; 4];
-    for location in new_locations {
-        if location[0] < x_width / 2 && location[1] < y_width / 2 {
-            counts[0] += 1;
-        } else if location[0] < x_width / 2 && location[1] > y_width / 2 {
-            counts[1] += 1;
-        } else if location[0] > x_width / 2 && location[1] < y_width / 2 {
-            counts[2] += 1;
-        } else if location[0] > x_width / 2 && location[1] > y_width / 2 {
-            counts[3] += 1;
+    // Recorded here rather than by the runner, which records from the start
+    let tree_path = if around_tree {
+        let Some(path) = args.frames.take() else {
+            eprintln!("--around-tree needs --frames\n\n{}", usage);
+            return ExitCode::from(2);
+        };
+        Some(path)
+    } else {
+        None
+    };
+
+    // Read once for both the solver and the recording, as it may come from stdin
+    let file_str = match read_input(Day14::DAY, &args.input) {
+        Ok(file_str) => file_str,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
         }
+    };
+    if run_on::<Day14>(&args, &file_str) == ExitCode::FAILURE {
+        return ExitCode::FAILURE;
     }
 
-    println!("Part 1: {:?}", counts[0] * counts[1] * counts[2] * counts[3]);
-}
-
-
-fn part2(robots: &Vec<State>, x_width: i64, y_width: i64) {
-    let mut new_locations = HashSet::new();
-    let mut steps = 0;
-    loop {
-        new_locations.clear();
-        for robot in robots {
-            let new_pos = robot.predict_position(steps, x_width, y_width);
-            if new_locations.contains(&new_pos) {
-                break;
-            }
-            new_locations.insert(new_pos);
+    let Some(path) = tree_path else {
+        return ExitCode::SUCCESS;
+    };
+    match record_tree(&file_str, &path, args.every) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
         }
-
-        // Check for robots in distinct locations
-        if new_locations.len() == robots.len() {
-            break;
-        }
-        steps += 1;
     }
-
-    println!("Part 2: {:?}", steps);
 }
-
-
-fn get_values(s: &str) -> [i64; 2] {
-    s.split(',')
-        .map(|s| s.trim_start().parse().unwrap())
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap()
-}
-
-fn main() -> Result<(), Box<dyn Error>> {
-
-    // Read in example
-    let file_str: String = fs::read_to_string("data/day14/day14.txt")?;
-
-    let mut robots = Vec::new();
-    for line in file_str.lines() {
-        let pv = line.split_ascii_whitespace().collect::<Vec<&str>>();
-        robots.push(State {
-            pos: get_values(&pv[0][2..]),
-            vel: get_values(&pv[1][2..]),
-        });
-    }
-
-    // part1(&robots, 100, 11, 7);
-    part1(&robots, 100, 101, 103);
-    part2(&robots, 101, 103);
-
-    Ok(())
-}
\ No newline at end of file
@@ -1,144 +1,168 @@
-use std::collections::HashSet;
+use aoc24::days::day17::{assemble, disassemble, pseudo_code, Breakpoint, Day17, Debugger, Stop};
+use aoc24::input::{read_input, InputSource};
+use aoc24::runner::{run_on, DayArgs, DAY_USAGE};
+use aoc24::Solution;
+use std::env;
 use std::error::Error;
-use std::fs;
-
-
-fn combo(operand: u8,
-         register_a: u64,
-         register_b: u64,
-         register_c: u64) -> u64 {
-    match operand {
-        0..4 => operand as u64,
-        4 => register_a,
-        5 => register_b,
-        6 => register_c,
-        _ => unreachable!(),
-    }
+use std::process::ExitCode;
+
+const TOOLS_USAGE: &str = "
+      --disassemble    Print the program as mnemonics and as pseudo-code before solving
+      --assemble FILE  Print the puzzle input for an assembly program (`-` for stdin) instead of solving
+      --trace          Print every instruction run with the registers before and after
+      --break ADDR     Show the registers and output before running the instruction at ADDR (repeatable)
+      --break-output N Show the registers and output once N values are output (repeatable)
+      --max-steps N    Stop debugging after N instructions, and skip solving (default: 1000000)";
+
+const DEFAULT_MAX_STEPS: usize = 1_000_000;
+
+/// Extra tools for looking at the program of the input.
+struct Tools {
+    disassemble: bool,
+    assemble: Option<InputSource>,
+    trace: bool,
+    breakpoints: Vec<Breakpoint>,
+    max_steps: usize,
 }
 
+/// Number following an option.
+fn take_number(args: &mut Vec<String>, i: usize, option: &str) -> Result<usize, String> {
+    (i < args.len()).then(|| args.remove(i))
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| format!("{} needs a number", option))
+}
 
-fn run_program(register_a: u64,
-               register_b: u64,
-               register_c: u64,
-               instructions: &Vec<[u8; 2]>) -> (u64, u64, u64, Vec<u8>) {
-    let mut reg_a = register_a;
-    let mut reg_b = register_b;
-    let mut reg_c = register_c;
-
-    let mut pointer = 0;
-    let mut output = Vec::new();
-
-    loop {
-        let instruction = instructions[pointer];
-        let opcode = instruction[0];
-        let operand = instruction[1];
-        let combo_operand = combo(operand, reg_a, reg_b, reg_c);
-        let mut increment = 1;
-
-        match opcode {
-            // adv
-            0 => reg_a = reg_a / 2_u64.pow(combo_operand as u32),
-            // bxl
-            1 => reg_b = reg_b ^ operand as u64,
-            // bst
-            2 => reg_b = combo_operand % 8,
-            // jnz
-            3 => if reg_a > 0 {
-                pointer = operand as usize;
-                increment = 0;
-            },
-            // bxc
-            4 => reg_b = reg_b ^ reg_c,
-            // out
-            5 => {
-                output.push((combo_operand % 8) as u8);
+impl Tools {
+    /// Take the tool options out of the arguments, leaving the rest.
+    fn take(args: &mut Vec<String>) -> Result<Self, String> {
+        let mut tools = Tools {
+            disassemble: false,
+            assemble: None,
+            trace: false,
+            breakpoints: Vec::new(),
+            max_steps: DEFAULT_MAX_STEPS,
+        };
+        let mut i = 0;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--disassemble" => {
+                    args.remove(i);
+                    tools.disassemble = true;
+                }
+                "--assemble" => {
+                    args.remove(i);
+                    let source = (i < args.len()).then(|| args.remove(i))
+                        .ok_or("--assemble needs a file")?;
+                    tools.assemble = Some(InputSource::from_arg(Some(&source)));
+                }
+                "--trace" => {
+                    args.remove(i);
+                    tools.trace = true;
+                }
+                "--break" => {
+                    args.remove(i);
+                    tools.breakpoints.push(Breakpoint::Pointer(take_number(args, i, "--break")?));
+                }
+                "--break-output" => {
+                    args.remove(i);
+                    tools.breakpoints.push(Breakpoint::Outputs(take_number(args, i, "--break-output")?));
+                }
+                "--max-steps" => {
+                    args.remove(i);
+                    tools.max_steps = take_number(args, i, "--max-steps")?;
+                }
+                _ => i += 1,
             }
-            // bdv
-            6 => reg_b = reg_a / 2_u64.pow(combo_operand as u32),
-            // cdv
-            7 => reg_c = reg_a / 2_u64.pow(combo_operand as u32),
-            _ => unreachable!()
         }
 
-        pointer += increment;
-        if pointer >= instructions.len() {
-            break;
-        }
+        Ok(tools)
     }
 
-    (reg_a, reg_b, reg_c, output)
-}
-
+    fn assemble(source: &InputSource) -> Result<(), Box<dyn Error>> {
+        let program = assemble(&read_input(Day17::DAY, source)?)?;
+        print!("{}", program);
 
-fn part1(register_a: u64,
-         register_b: u64,
-         register_c: u64,
-         instructions: &Vec<[u8; 2]>) {
-    let output = run_program(register_a, register_b, register_c, instructions);
-    let string = output.3.iter()
-        .map(|b| b.to_string())
-        .collect::<Vec<String>>()
-        .join(",");
+        Ok(())
+    }
 
-    println!("Part 1: {}", string);
-}
+    /// Run the tools on the input, returning how the debugged program stopped, if it was run.
+    fn run(&self, file_str: &str) -> Result<Option<Stop>, Box<dyn Error>> {
+        let program = Day17::parse(file_str)?;
+        if self.disassemble {
+            println!("{}", disassemble(&program.instructions));
+            println!("{}", pseudo_code(&program.instructions));
+        }
+        if !self.trace && self.breakpoints.is_empty() {
+            return Ok(None);
+        }
 
-fn part2(register_b: u64,
-         register_c: u64,
-         instructions: &Vec<[u8; 2]>) {
-    let expected = instructions.iter()
-        .map(|instruction| instruction.clone().into_iter())
-        .flatten()
-        .collect::<Vec<u8>>();
-
-    // Run the program until the last jump
-    // Then the last instruction prints register B mod 8
-    // Find values that give the register B that we want for each digit.
-    // Multiply possible reg A values by 8 at each iteration and check 0..8 around those
-    let all_but_last = instructions[..instructions.len() - 2].to_vec();
-
-    let mut valid_reg = HashSet::<u64>::new();
-    valid_reg.insert(0);
-    for output_index in (0..expected.len()).rev() {
-        let mut new_valid = HashSet::new();
-        for reg in valid_reg.iter() {
-            for i in 0..8 {
-                let output = run_program(reg + i, register_b, register_c, &all_but_last);
-                let reg_b = (output.1 % 8) as u8;
-
-                if reg_b == expected[output_index] {
-                    new_valid.insert(8 * (reg + i));
-                }
+        // Show each breakpoint reached and carry on, up to the step limit overall
+        let mut debugger = Debugger::new(&program);
+        self.breakpoints.iter().for_each(|&breakpoint| { debugger.add_breakpoint(breakpoint); });
+        let stop = loop {
+            let stop = debugger.run(self.max_steps - debugger.steps, |step| if self.trace {
+                println!("{}", step);
+            });
+            if !matches!(stop, Stop::Breakpoint(_)) {
+                break stop;
             }
-        }
-        valid_reg = new_valid;
-    }
+            println!("Stopped at {}: {}, output {:?}", stop, debugger.registers, debugger.output);
+        };
+        println!("Stopped after {} steps: {}, output {:?}", debugger.steps, stop, debugger.output);
+        println!();
 
-    let min_reg = valid_reg.iter().min().unwrap() / 8;
-    println!("Part 2: {}", min_reg);
+        Ok(Some(stop))
+    }
 }
 
 
-fn main() -> Result<(), Box<dyn Error>> {
-    // Read in example
-    let file_str: String = fs::read_to_string("data/day17/day17.txt")?;
-
-    let mut lines = file_str.lines();
-    let register_a = lines.next().unwrap()[12..].parse::<u64>()?;
-    let register_b = lines.next().unwrap()[12..].parse::<u64>()?;
-    let register_c = lines.next().unwrap()[12..].parse::<u64>()?;
+fn main() -> ExitCode {
+    let usage = format!("{}{}", DAY_USAGE, TOOLS_USAGE);
+    let mut env_args = env::args().skip(1).collect::<Vec<_>>();
+    let tools = match Tools::take(&mut env_args) {
+        Ok(tools) => tools,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, usage);
+            return ExitCode::from(2);
+        }
+    };
+    let args = DayArgs::from_args(&env_args, &usage);
+    if let Some(arg) = args.extra.first() {
+        eprintln!("Unexpected argument: {}\n\n{}", arg, usage);
+        return ExitCode::from(2);
+    }
 
-    lines.next();
-    let instructions = lines.next().unwrap()[9..].bytes().enumerate()
-        .filter(|&(i, _)| i % 2 == 0)
-        .map(|(_, b)| b - b'0')
-        .collect::<Vec<_>>()
-        .chunks(2)
-        .map(|c| [c[0], c[1]])
-        .collect::<Vec<_>>();
+    if let Some(source) = &tools.assemble {
+        return match Tools::assemble(source) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
 
-    part1(register_a, register_b, register_c, &instructions);
-    part2(register_b, register_c, &instructions);
+    // Read once for both the tools and the solver, as it may come from stdin
+    let file_str = match read_input(Day17::DAY, &args.input) {
+        Ok(file_str) => file_str,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Before solving, so that programs the solver cannot handle can still be read
+    match tools.run(&file_str) {
+        Ok(None | Some(Stop::Halted)) => (),
+        Ok(Some(stop)) => {
+            eprintln!("Not solving a program that stopped with {}", stop);
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    }
 
-    Ok(())
-}
\ No newline at end of file
+    run_on::<Day17>(&args, &file_str)
+}
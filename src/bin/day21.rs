@@ -0,0 +1,6 @@
+use aoc24::days::day21::Day21;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc24::run::<Day21>()
+}
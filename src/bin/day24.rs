@@ -1,129 +1,134 @@
-use std::cmp::Reverse;
-use std::collections::{HashMap, HashSet};
+use aoc24::days::day24::{build_netlist, input_vectors, parse_netlist, suspicious_wires, Day24};
+use aoc24::input::read_input;
+use aoc24::runner::{run_on, DayArgs, DAY_USAGE};
+use aoc24::Solution;
+use std::env;
 use std::error::Error;
-use std::fs;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const EXPORT_USAGE: &str = "
+      --dot FILE       Write the gates as a Graphviz graph, suspicious gates in red
+      --verilog FILE   Write the gates as a structural Verilog module
+      --vcd FILE       Write a waveform of the gates settling, one time unit per gate
+      --add X+Y        Also add X and Y in the waveform (repeatable)";
+
+/// Where to export the netlist of the input.
+#[derive(Default)]
+struct Exports {
+    dot: Option<PathBuf>,
+    verilog: Option<PathBuf>,
+    vcd: Option<PathBuf>,
+    /// Numbers to add in the waveform after the initial values.
+    additions: Vec<(u128, u128)>,
+}
 
-fn decimal_value(binary: &[bool]) -> u64 {
-    let mut val = 0;
-    for (i, &bit) in binary.iter().rev().enumerate() {
-        if bit {
-            val += 2_u64.pow(i as u32);
+impl Exports {
+    /// Take the export options out of the arguments, leaving the rest.
+    fn take(args: &mut Vec<String>) -> Result<Self, String> {
+        let mut exports = Exports::default();
+        let mut i = 0;
+        while i < args.len() {
+            let target = match args[i].as_str() {
+                "--dot" => &mut exports.dot,
+                "--verilog" => &mut exports.verilog,
+                "--vcd" => &mut exports.vcd,
+                "--add" => {
+                    args.remove(i);
+                    let addition = (i < args.len()).then(|| args.remove(i))
+                        .and_then(|sum| sum.split_once('+')
+                            .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?))))
+                        .ok_or("--add needs two numbers, X+Y")?;
+                    exports.additions.push(addition);
+                    continue;
+                }
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+            let option = args.remove(i);
+            if i == args.len() {
+                return Err(format!("{} needs a file", option));
+            }
+            *target = Some(PathBuf::from(args.remove(i)));
         }
-    }
-
-    val
-}
 
-fn decimal_to_bits(n: u64) -> Vec<bool> {
-    let mut bits = Vec::new();
-    for i in 0..46 {
-        let bit = ((n >> i) & 1) == 1;
-        bits.push(bit);
+        Ok(exports)
     }
-    bits.reverse();
-    bits
-}
 
-fn extract_value(values: &HashMap<String, bool>, query: char) -> u64 {
-    let mut sorted_values = values.iter()
-        .filter(|&(key, _)| key.chars().collect::<Vec<_>>()[0] == query)
-        .collect::<Vec<_>>();
-    sorted_values.sort_by_key(|&(key, _)| Reverse(key));
-    let bin_values = sorted_values.iter()
-        .map(|&(_, val)| val)
-        .cloned()
-        .collect::<Vec<_>>();
-
-    decimal_value(&bin_values)
-}
+    fn write(&self, file_str: &str) -> Result<(), Box<dyn Error>> {
+        if self.dot.is_none() && self.verilog.is_none() && self.vcd.is_none() {
+            return Ok(());
+        }
 
-fn part1(initial_values: &HashMap<String, bool>, operations: &HashMap<[String; 3], String>) -> u64 {
-    let targets = operations.keys()
-        .map(|key| key[2].clone())
-        .filter(|v| v.chars().collect::<Vec<_>>()[0] == 'z')
-        .collect::<HashSet<_>>();
+        let (initial_values, operations) = parse_netlist(file_str)?;
+        let netlist = build_netlist(&initial_values, &operations)?;
 
-    let mut values = initial_values.clone();
-    while !values.keys().cloned().collect::<HashSet<_>>().is_superset(&targets) {
-        for operator in operations.keys() {
-            if values.contains_key(&operator[0]) && values.contains_key(&operator[1]) {
-                let left = values.get(&operator[0]).unwrap();
-                let right = values.get(&operator[1]).unwrap();
-                let result = match operations.get(operator).unwrap().as_str() {
-                    "AND" => left & right,
-                    "OR" => left | right,
-                    "XOR" => left ^ right,
-                    _ => unreachable!(),
-                };
-                values.insert(operator[2].clone(), result);
-            }
+        if let Some(path) = &self.dot {
+            let highlight = suspicious_wires(&initial_values, &operations).iter()
+                .filter_map(|wire| netlist.wire(wire))
+                .collect::<Vec<_>>();
+            fs::write(path, netlist.to_dot(&highlight))
+                .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+            println!("Wrote {}", path.display());
+        }
+        if let Some(path) = &self.verilog {
+            fs::write(path, netlist.to_verilog("day24"))
+                .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+            println!("Wrote {}", path.display());
+        }
+        if let Some(path) = &self.vcd {
+            let vectors = input_vectors(&netlist, &initial_values, &self.additions);
+            File::create(path)
+                .and_then(|file| {
+                    let mut out = BufWriter::new(file);
+                    netlist.write_vcd("day24", &vectors, &mut out)?;
+                    out.flush()
+                })
+                .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+            println!("Wrote {}", path.display());
         }
-    }
 
-    // Extract values from target
-    extract_value(&values, 'z')
+        Ok(())
+    }
 }
 
-fn read_file(
-    file_name: &str
-) -> Result<(HashMap<String, bool>, HashMap<[String; 3], String>), Box<dyn Error>> {
-    let file_str: String = fs::read_to_string(file_name)?;
-    let mut lines = file_str.lines();
-
-    let mut initial_values = HashMap::new();
-    loop {
-        let line = lines.next().unwrap();
-        if line.is_empty() {
-            break;
+fn main() -> ExitCode {
+    let usage = format!("{}{}", DAY_USAGE, EXPORT_USAGE);
+    let mut env_args = env::args().skip(1).collect::<Vec<_>>();
+    let exports = match Exports::take(&mut env_args) {
+        Ok(exports) => exports,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, usage);
+            return ExitCode::from(2);
         }
-        let lr = line.split(": ").collect::<Vec<&str>>();
-        initial_values.insert(lr[0].to_string(), lr[1] == "1");
+    };
+    let args = DayArgs::from_args(&env_args, &usage);
+    if let Some(arg) = args.extra.first() {
+        eprintln!("Unexpected argument: {}\n\n{}", arg, usage);
+        return ExitCode::from(2);
     }
 
-    let mut operations = HashMap::new();
-    for line in lines {
-        let lr = line.split(" -> ").collect::<Vec<&str>>();
-        let op = lr[0].split(" ").collect::<Vec<&str>>();
-        operations.insert([op[0].to_string(), op[2].to_string(), lr[1].to_string()], op[1].to_string());
+    // Read once for both the solver and the exports, as it may come from stdin
+    let file_str = match read_input(Day24::DAY, &args.input) {
+        Ok(file_str) => file_str,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if run_on::<Day24>(&args, &file_str) == ExitCode::FAILURE {
+        return ExitCode::FAILURE;
     }
 
-    Ok((initial_values, operations))
-}
-
-
-fn main() -> Result<(), Box<dyn Error>> {
-
-    // Read in example
-    let (initial_values, operations) = read_file("data/day24/day24.txt")?;
-
-    let z_value = part1(&initial_values, &operations);
-    println!("Part 1: {}", z_value);
-
-
-    // Read in modified example (manually adjusted)
-    let (initial_values, operations) = read_file("data/day24/day24-modified.txt")?;
-
-    let z_value = part1(&initial_values, &operations);
-
-    // Extract x and y values from target
-    let x_value = extract_value(&initial_values, 'x');
-    let y_value = extract_value(&initial_values, 'y');
-    let expected_z = x_value + y_value;
-
-    let bits = decimal_to_bits(z_value);
-    print! {"z - file: "}
-    bits.iter().for_each(|&x| if x { print!("1") } else { print!("0") });
-    println!();
-
-    print! {"z -  exp: "}
-    let bits = decimal_to_bits(expected_z);
-    bits.iter().for_each(|&x| if x { print!("1") } else { print!("0") });
-    println!();
-
-    let mut ans = vec!["wpd", "z11", "skh", "jqf", "z19", "mdd", "z37", "wts"];
-    ans.sort();
-    ans.iter().for_each(|&x| print!("{},", x));
-    println!();
-
-    Ok(())
+    match exports.write(&file_str) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
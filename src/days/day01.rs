@@ -0,0 +1,77 @@
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+use std::collections::HashMap;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Parse into columns
+        let mut col1 = Vec::new();
+        let mut col2 = Vec::new();
+        for line in lines(Self::DAY, input) {
+            let pair = line.parse_fields::<i32>()?;
+            if pair.len() != 2 {
+                return Err(line.error_at(line.text, "expected two numbers"));
+            }
+
+            col1.push(pair[0]);
+            col2.push(pair[1]);
+        }
+
+        Ok((col1, col2))
+    }
+
+    fn part1((col1, col2): &Self::Input) -> Answer {
+        // Sort column data
+        let mut col1_sorted = col1.clone();
+        col1_sorted.sort();
+
+        let mut col2_sorted = col2.clone();
+        col2_sorted.sort();
+
+        // Add up differences
+        col1_sorted.into_iter().zip(col2_sorted)
+            .fold(0, |acc, (a, b)| acc + (a - b).abs()).into()
+    }
+
+    fn part2((col1, col2): &Self::Input) -> Answer {
+        // Count up distinct values in col2
+        let mut col2_map = HashMap::new();
+        col2.iter()
+            .for_each(|val| *col2_map.entry(val).or_insert(0) += 1);
+
+        // Sum up matches from col1
+        col1.iter()
+            .fold(0, |acc, val| acc + val * col2_map.get(val).unwrap_or(&0)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    #[test]
+    fn part1_example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part1(&input), Answer::Integer(11));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part2(&input), Answer::Integer(31));
+    }
+}
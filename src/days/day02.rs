@@ -0,0 +1,140 @@
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day02;
+
+pub fn is_report_safe(report: &[i32]) -> bool {
+    // With fewer than two levels there is no change to be unsafe
+    if report.len() < 2 {
+        return true;
+    }
+    let is_increasing = *report.last().unwrap() > report[0];
+
+    let min_increase = report.windows(2)
+        .map(|window| window[1] - window[0])
+        .min()
+        .unwrap();
+
+    let max_increase = report.windows(2)
+        .map(|window| window[1] - window[0])
+        .max()
+        .unwrap();
+
+    if is_increasing {
+        min_increase >= 1 && max_increase <= 3
+    } else {
+        min_increase >= -3 && max_increase <= -1
+    }
+}
+
+pub fn is_report_safe_part2(report: &[i32]) -> bool {
+    if is_report_safe(report) {
+        true
+    } else {
+
+        // Remove each element in turn and test for being safe
+        for i in 0..report.len() {
+            let mut report_rem = report.to_vec();
+            report_rem.remove(i);
+
+            if is_report_safe(&report_rem) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Parse into reports
+        let mut reports = Vec::new();
+        for line in lines(Self::DAY, input) {
+            let report = line.parse_fields::<i32>()?;
+            // A single level is a (safe) report, but a blank line is not
+            if report.is_empty() {
+                return Err(line.error_at(line.text, "expected levels"));
+            }
+
+            reports.push(report);
+        }
+
+        Ok(reports)
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        reports.iter()
+            .filter(|report| is_report_safe(report))
+            .count().into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        reports.iter()
+            .filter(|report| is_report_safe_part2(report))
+            .count().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn part1_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part1(&input), Answer::Integer(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part2(&input), Answer::Integer(4));
+    }
+
+    #[test]
+    fn safe_reports() {
+        assert!(is_report_safe(&[7, 6, 4, 2, 1]));
+        assert!(is_report_safe(&[1, 3, 6, 7, 9]));
+        assert!(!is_report_safe(&[1, 2, 7, 8, 9]));
+        assert!(!is_report_safe(&[8, 6, 4, 4, 1]));
+        assert!(!is_report_safe(&[1, 3, 2, 4, 5]));
+    }
+
+    #[test]
+    fn safe_reports_with_dampener() {
+        assert!(is_report_safe_part2(&[1, 3, 2, 4, 5]));
+        assert!(is_report_safe_part2(&[8, 6, 4, 4, 1]));
+        assert!(!is_report_safe_part2(&[1, 2, 7, 8, 9]));
+        assert!(!is_report_safe_part2(&[9, 7, 6, 2, 1]));
+    }
+
+    #[test]
+    fn short_reports() {
+        assert!(is_report_safe(&[5]));
+        assert!(is_report_safe(&[]));
+        assert!(!is_report_safe(&[1, 9]));
+        assert!(is_report_safe_part2(&[1, 9]));
+
+        let input = Day02::parse("1 9").unwrap();
+        assert_eq!(Day02::part1(&input), Answer::Integer(0));
+        assert_eq!(Day02::part2(&input), Answer::Integer(1));
+
+        let input = Day02::parse("5\n1 2 3").unwrap();
+        assert_eq!(Day02::part1(&input), Answer::Integer(2));
+        assert_eq!(Day02::part2(&input), Answer::Integer(2));
+        assert!(Day02::parse("1 2\n\n3 4").is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::parse::ParseError;
+use crate::{Answer, Solution};
+use regex::Regex;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(code: &Self::Input) -> Answer {
+        // Form regex matching mul
+        let regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+        // Sum up multiplications
+        regex.captures_iter(code)
+            .map(|c| c.extract())
+            .fold(0, |acc, (_, [a, b])| acc + a.parse::<i32>().unwrap() * b.parse::<i32>().unwrap()).into()
+    }
+
+    fn part2(code: &Self::Input) -> Answer {
+        // Form regex's for our patterns
+        let do_regex = Regex::new(r"^do\(\)").unwrap();
+        let dont_regex = Regex::new(r"^don't\(\)").unwrap();
+        let mul_regex = Regex::new(r"^mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+        // Loop over code looking for matches
+        let mut do_mul = true;
+        let mut sum = 0;
+        for i in 0..code.len() {
+            if !do_mul && do_regex.is_match(&code[i..]) {
+                do_mul = true;
+            } else if do_mul && dont_regex.is_match(&code[i..]) {
+                do_mul = false;
+            } else if do_mul && mul_regex.is_match(&code[i..]) {
+                let (_, [a, b]) = mul_regex.captures(&code[i..]).unwrap().extract();
+                sum += a.parse::<i32>().unwrap() * b.parse::<i32>().unwrap();
+            }
+        }
+
+        sum.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const EXAMPLE_2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn part1_example() {
+        let input = Day03::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day03::part1(&input), Answer::Integer(161));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day03::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day03::part2(&input), Answer::Integer(48));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::grid::{Grid, Point};
+use crate::parse::ParseError;
+use crate::{Answer, Solution};
+
+pub struct Day04;
+
+/// Whether a word appears starting at a point, reading in steps of `step`.
+pub fn match_word(puzzle: &Grid<char>, word: &[char], start: Point, step: Point) -> bool {
+    word.iter().enumerate()
+        .all(|(i, letter)| puzzle.get(start + step * i as i32) == Some(letter))
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, input, "a letter", Some)
+    }
+
+    fn part1(puzzle: &Self::Input) -> Answer {
+        let word = ['X', 'M', 'A', 'S'];
+
+        // Loop over each location and direction
+        puzzle.points()
+            .map(|point| Point::NEIGHBOURS_8.iter()
+                .filter(|step| match_word(puzzle, &word, point, **step))
+                .count())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(puzzle: &Self::Input) -> Answer {
+        let word = ['M', 'A', 'S'];
+
+        // Look for valid X-MAS combinations, with the top-left corner at each location
+        puzzle.points()
+            .filter(|&point| {
+                (match_word(puzzle, &word, point, Point::new(1, 1)) ||
+                    match_word(puzzle, &word, point + Point::new(2, 2), Point::new(-1, -1))) &&
+                    (match_word(puzzle, &word, point + Point::new(2, 0), Point::new(-1, 1)) ||
+                        match_word(puzzle, &word, point + Point::new(0, 2), Point::new(1, -1)))
+            })
+            .count()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn part1_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part1(&input), Answer::Integer(18));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part2(&input), Answer::Integer(9));
+    }
+}
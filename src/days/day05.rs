@@ -0,0 +1,168 @@
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day05;
+
+/// Each rule maps a page to the pages that must come -before- it.
+pub type Rules = HashMap<i32, Vec<i32>>;
+
+pub fn check_rule(rules: &[i32], pages: &HashSet<&i32>) -> bool {
+    for rule in rules {
+        if pages.contains(&rule) {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub fn is_correct_order(rules: &Rules, page_order: &[i32]) -> bool {
+    for i in 0..page_order.len() {
+        if let Some(rule) = rules.get(&page_order[i]) {
+
+            // If the page is in the rules, then check remaining pages against the rule
+            let remaining_pages = page_order[i + 1..].iter()
+                .collect::<HashSet<_>>();
+
+            if !remaining_pages.is_empty() && !check_rule(rule, &remaining_pages) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+pub fn shuffle(rules: &Rules, page_order: &[i32]) -> Vec<i32> {
+    let mut new_order = page_order.to_vec();
+
+    for i in 0..new_order.len() {
+        if let Some(rule) = rules.get(&new_order[i]) {
+
+            // The rule to check
+            let rule = rule.iter()
+                .collect::<HashSet<&i32>>();
+
+            // If the page is in the rules, then check remaining pages against the rule
+            for j in (i + 1)..new_order.len() {
+                // If there's a violating rule, swap the order
+                if rule.contains(&new_order[j]) {
+                    new_order.swap(i, j);
+                    break;
+                }
+            }
+        }
+    }
+
+    new_order
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Rules, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rules = HashMap::new();
+        let mut page_orders = Vec::new();
+
+        // Parse into rules and page orders
+        // Each rule contains a vec of pages that must come -before-
+        let mut is_rules = true;
+        for line in lines(Self::DAY, input) {
+            if line.text.is_empty() {
+                is_rules = false;
+            } else if is_rules {
+                let (before, after) = line.split_once(line.text, "|")?;
+                let before = line.parse::<i32>(before)?;
+                let after = line.parse::<i32>(after)?;
+
+                rules.entry(after).or_insert(Vec::new()).push(before);
+            } else {
+                let order = line.text.split(',')
+                    .map(|s| line.parse::<i32>(s))
+                    .collect::<Result<Vec<i32>, _>>()?;
+                page_orders.push(order);
+            }
+        }
+
+        Ok((rules, page_orders))
+    }
+
+    fn part1((rules, page_orders): &Self::Input) -> Answer {
+        let mut middle_sum = 0;
+        for page_order in page_orders {
+            if is_correct_order(rules, page_order) {
+                // Find middle page number
+                middle_sum += page_order[(page_order.len() - 1) / 2];
+            }
+        }
+
+        middle_sum.into()
+    }
+
+    fn part2((rules, page_orders): &Self::Input) -> Answer {
+        let mut middle_sum = 0;
+        for page_order in page_orders {
+            if !is_correct_order(rules, page_order) {
+                // If it's not in the correct order, keep shuffling until it is
+                let mut new_order = shuffle(rules, page_order);
+                while !is_correct_order(rules, &new_order) {
+                    new_order = shuffle(rules, &new_order);
+                }
+
+                middle_sum += new_order[(page_order.len() - 1) / 2];
+            }
+        }
+
+        middle_sum.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn part1_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part1(&input), Answer::Integer(143));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part2(&input), Answer::Integer(123));
+    }
+}
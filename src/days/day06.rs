@@ -0,0 +1,246 @@
+use crate::grid::{Direction, Grid, Point};
+use crate::parse::{check_single, ParseError};
+use crate::render::{Cell, Colour, Frames, Picture};
+use crate::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day06;
+
+pub fn find_guard(map: &Grid<char>) -> Point {
+    map.find(&'^').expect("No guard found")
+}
+
+pub fn move_guard(map: &Grid<char>,
+                  location: Point,
+                  direction: Direction,
+                  visited: &mut HashSet<Point>) -> Option<Point> {
+    let mut new_location = location;
+    loop {
+        let next = new_location + direction.offset();
+        match map.get(next) {
+            None => return None,
+            Some('#') => break,
+            Some(_) => {}
+        }
+        new_location = next;
+        visited.insert(new_location);
+    }
+
+    Some(new_location)
+}
+
+
+/// Visited (location, direction) pairs, used to detect the guard walking in a loop.
+pub type VisitedSteps = HashSet<(Point, Direction)>;
+
+pub enum CycleResult {
+    OutOfBounds,
+    NewLocation(Point),
+    Cycle,
+}
+
+
+pub fn move_guard_with_step(map: &Grid<char>,
+                            location: Point,
+                            direction: Direction,
+                            visited: &mut VisitedSteps) -> CycleResult {
+    let mut new_location = location;
+    loop {
+        let next = new_location + direction.offset();
+        match map.get(next) {
+            None => return CycleResult::OutOfBounds,
+            Some('#') => break,
+            Some(_) => {}
+        }
+        new_location = next;
+        if visited.contains(&(new_location, direction)) {
+            return CycleResult::Cycle;
+        }
+        visited.insert((new_location, direction));
+    }
+
+    CycleResult::NewLocation(new_location)
+}
+
+
+pub fn test_for_cycle(map: &Grid<char>,
+                      initial_location: Point) -> bool {
+    // Initial location and direction
+    let mut location = initial_location;
+    let mut direction = Direction::North;
+
+    // Set of visited locations
+    let mut visited = HashSet::new();
+    visited.insert((location, direction));
+
+    // Keep moving and turning right
+    loop {
+        match move_guard_with_step(map, location, direction, &mut visited) {
+            CycleResult::Cycle => return true,
+            CycleResult::NewLocation(value) => location = value,
+            CycleResult::OutOfBounds => break,
+        }
+        direction = direction.turn_right();
+    }
+
+    false
+}
+
+
+pub fn find_locations(map: &Grid<char>,
+                      initial_location: Point) -> HashSet<Point> {
+    // Initial location
+    let mut location = initial_location;
+
+    // Set of visited locations
+    let mut visited = HashSet::new();
+    visited.insert(location);
+
+    // Keep moving and turning right
+    let mut direction = Direction::North;
+    while let Some(value) = move_guard(map, location, direction, &mut visited) {
+        location = value;
+        direction = direction.turn_right();
+    }
+
+    visited
+}
+
+/// Every location of the guard in order, from the start until leaving the map.
+pub fn route(map: &Grid<char>,
+             initial_location: Point) -> Vec<Point> {
+    let mut route = vec![initial_location];
+    let mut location = initial_location;
+    let mut direction = Direction::North;
+    loop {
+        let next = location + direction.offset();
+        match map.get(next) {
+            None => break,
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => {
+                location = next;
+                route.push(location);
+            }
+        }
+    }
+
+    route
+}
+
+pub fn picture(map: &Grid<char>) -> Picture {
+    Picture::new(map, |&c| match c {
+        '#' => Cell::new('#', Colour::GREY),
+        '^' => Cell::new('^', Colour::YELLOW),
+        _ => Cell::new('.', Colour::DARK_GREY),
+    })
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(Self::DAY, input, "`.`, `#` or `^`",
+                              |c| matches!(c, '.' | '#' | '^').then_some(c))?;
+        check_single(Self::DAY, input, &map, &'^', "guard `^`")?;
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        // Initial location
+        let initial_location = find_guard(map);
+
+        // Set of visited locations
+        let visited = find_locations(map, initial_location);
+
+        // The number of visited locations
+        visited.len().into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        // Initial location
+        let initial_location = find_guard(map);
+
+        // Set of visited locations
+        let visited = find_locations(map, initial_location);
+
+        // Try each original visited location for new obstacle location
+        let mut cycle_count = 0;
+        for location in visited {
+            if location != initial_location {
+                let mut test_map = map.clone();
+                test_map[location] = '#';
+                if test_for_cycle(&test_map, initial_location) { cycle_count += 1 }
+            }
+        }
+
+        // The number locations that result in a cycle
+        cycle_count.into()
+    }
+
+    fn render(map: &Self::Input) -> Option<Picture> {
+        let mut picture = picture(map);
+        picture.path(&route(map, find_guard(map)), Colour::RED);
+
+        Some(picture)
+    }
+
+    fn frames(map: &Self::Input, every: usize) -> Option<Frames<'_>> {
+        // The route so far at every `every`th step, with the guard at its end
+        let route = route(map, find_guard(map));
+        let background = picture(map);
+
+        Some(Box::new((0..route.len()).step_by(every).map(move |step| {
+            let guard = step.checked_sub(1)
+                .and_then(|previous| Direction::from_offset(route[step] - route[previous]))
+                .unwrap_or(Direction::North);
+
+            let mut picture = background.clone();
+            picture.path(&route[..=step], Colour::RED)
+                .overlay([route[step]], Cell::new(guard.arrow(), Colour::YELLOW));
+            (step, picture)
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn part1_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part1(&input), Answer::Integer(41));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part2(&input), Answer::Integer(6));
+    }
+
+    #[test]
+    fn route_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        let route = route(&input, find_guard(&input));
+        assert_eq!(route.iter().collect::<HashSet<_>>().len(), 41);
+        assert_eq!(route.last(), Some(&Point::new(9, 7)));
+
+        let picture = Day06::render(&input).unwrap().to_text();
+        assert_eq!(picture.lines().nth(1), Some("....>>>>v#"));
+    }
+}
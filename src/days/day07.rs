@@ -0,0 +1,140 @@
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day07;
+
+pub fn find_match(value: u64, target: u64, nums: &[u64]) -> bool {
+
+    // Check against the target if we're at the end of the equation
+    if nums.is_empty() {
+        return value == target;
+    }
+
+    // Only check for multiply if we're within the target
+    let test_mult = if target >= value * nums[0] {
+        find_match(value * nums[0], target, &nums[1..])
+    } else {
+        false
+    };
+
+    // Recursive depth-first search
+    test_mult || find_match(value + nums[0], target, &nums[1..])
+}
+
+
+pub fn concat_u64(left: u64, right: u64) -> u64 {
+    let digit_count = right.ilog10() + 1;
+
+    left * 10_u64.pow(digit_count) + right
+}
+
+
+pub fn find_match_concat(value: u64, target: u64, nums: &[u64]) -> bool {
+
+    // Check against the target if we're at the end of the equation
+    if nums.is_empty() {
+        return value == target;
+    }
+
+    // Only check for multiply if we're within the target
+    let test_mult = if target >= value * nums[0] {
+        find_match_concat(value * nums[0], target, &nums[1..])
+    } else {
+        false
+    };
+
+    // Recursive depth-first search
+    test_mult ||
+        find_match_concat(value + nums[0], target, &nums[1..]) ||
+        find_match_concat(concat_u64(value, nums[0]), target, &nums[1..])
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut puzzle = Vec::new();
+        for line in lines(Self::DAY, input) {
+            let (test_value, nums) = line.split_once(line.text, ":")?;
+            let test_value = line.parse::<u64>(test_value)?;
+            let nums = nums.split_ascii_whitespace()
+                .map(|num| line.parse::<u64>(num))
+                .collect::<Result<Vec<u64>, _>>()?;
+            if nums.is_empty() {
+                return Err(line.error_at(line.text, "expected numbers after `:`"));
+            }
+
+            puzzle.push((test_value, nums));
+        }
+
+        Ok(puzzle)
+    }
+
+    fn part1(puzzle: &Self::Input) -> Answer {
+        let mut calibration = 0;
+        for (value, nums) in puzzle {
+            if find_match(nums[0], *value, &nums[1..]) {
+                calibration += value;
+            }
+        }
+
+        calibration.into()
+    }
+
+    fn part2(puzzle: &Self::Input) -> Answer {
+        let mut calibration = 0;
+        for (value, nums) in puzzle {
+            if find_match_concat(nums[0], *value, &nums[1..]) {
+                calibration += value;
+            }
+        }
+
+        calibration.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn part1_example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part1(&input), Answer::Integer(3749));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part2(&input), Answer::Integer(11387));
+    }
+
+    #[test]
+    fn concat() {
+        assert_eq!(concat_u64(12, 345), 12345);
+        assert_eq!(concat_u64(15, 6), 156);
+        assert_eq!(concat_u64(1, 10), 110);
+    }
+
+    #[test]
+    fn matches() {
+        assert!(find_match(81, 3267, &[40, 27]));
+        assert!(!find_match(17, 83, &[5]));
+        assert!(!find_match(15, 156, &[6]));
+        assert!(find_match_concat(15, 156, &[6]));
+        assert!(find_match_concat(6, 7290, &[8, 6, 15]));
+    }
+}
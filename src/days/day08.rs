@@ -0,0 +1,115 @@
+use crate::grid::{Grid, Point};
+use crate::parse::ParseError;
+use crate::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day08;
+
+pub fn find_locations(map: &Grid<char>, frequency: char) -> Vec<Point> {
+    map.find_all(&frequency).collect()
+}
+
+/// Unique antenna frequencies on the map.
+pub fn find_frequencies(map: &Grid<char>) -> HashSet<char> {
+    let mut frequencies = map.iter()
+        .map(|(_, c)| *c)
+        .collect::<HashSet<_>>();
+    frequencies.remove(&'.');
+
+    frequencies
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, input, "`.` or an antenna",
+                    |c| (c == '.' || c.is_ascii_alphanumeric()).then_some(c))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        // Loop over each frequency
+        let mut antinodes = HashSet::new();
+        for frequency in find_frequencies(map) {
+            let locations = find_locations(map, frequency);
+
+            // Loop over each pair of locations
+            for l1 in 0..locations.len() {
+                for l2 in (l1 + 1)..locations.len() {
+                    let diff = locations[l1] - locations[l2];
+
+                    // Check for possible antinode locations
+                    let new_l1 = locations[l1] + diff;
+                    if map.contains(new_l1) {
+                        antinodes.insert(new_l1);
+                    }
+                    let new_l2 = locations[l2] - diff;
+                    if map.contains(new_l2) {
+                        antinodes.insert(new_l2);
+                    }
+                }
+            }
+        }
+
+        antinodes.len().into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        // Loop over each frequency
+        let mut antinodes = HashSet::new();
+        for frequency in find_frequencies(map) {
+            let locations = find_locations(map, frequency);
+
+            // Loop over each pair of locations
+            for l1 in 0..locations.len() {
+                for l2 in (l1 + 1)..locations.len() {
+                    let diff = locations[l1] - locations[l2];
+
+                    // Check for possible antinode locations (forward, then backward)
+                    for step in [diff, -diff] {
+                        let mut new_location = locations[l1];
+                        while map.contains(new_location) {
+                            antinodes.insert(new_location);
+                            new_location += step;
+                        }
+                    }
+                }
+            }
+        }
+
+        antinodes.len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn part1_example() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part1(&input), Answer::Integer(14));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part2(&input), Answer::Integer(34));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = lines(Self::DAY, input).next()
+            .filter(|line| !line.text.is_empty())
+            .ok_or_else(|| ParseError::end_of_input(Self::DAY, input, "expected a disk map"))?;
+
+        line.map_chars("a digit", |c| c.to_digit(10))
+    }
+
+    fn part1(disk: &Self::Input) -> Answer {
+        let disk_len = disk.iter().sum::<u32>();
+
+        // Decode disk
+        let mut decoded: Vec<Option<u32>> = vec![None; disk_len as usize];
+
+        let mut index = 0;
+        for (disk_index, val) in disk.iter().enumerate() {
+            if disk_index % 2 == 0 {
+                for i in 0..*val {
+                    decoded[(index + i) as usize] = Some(disk_index as u32 / 2);
+                }
+            }
+            index += val;
+        }
+
+        // Move file blocks from the back into the gaps at the front
+        let mut fwd_index = 0;
+        let mut bwd_index = decoded.len();
+        while fwd_index < bwd_index {
+            if decoded[fwd_index].is_some() {
+                fwd_index += 1;
+            } else if decoded[bwd_index - 1].is_none() {
+                bwd_index -= 1;
+            } else {
+                decoded.swap(fwd_index, bwd_index - 1);
+            }
+        }
+
+        // Calculate the checksum
+        let checksum = decoded.iter().enumerate()
+            .filter_map(|(index, value)| value.map(|id| index as u64 * id as u64))
+            .sum::<u64>();
+
+        checksum.into()
+    }
+
+    fn part2(disk: &Self::Input) -> Answer {
+
+        // Decode disk into blocks (size, value)
+        let mut blocks = Vec::new();
+
+        for (disk_index, val) in disk.iter().enumerate() {
+            if disk_index % 2 == 0 {
+                blocks.push((*val, Some(disk_index as u32 / 2)));
+            } else {
+                blocks.push((*val, None));
+            }
+        }
+
+        // Go backward through blocks
+        let mut bwd_index = blocks.len().saturating_sub(1);
+        while bwd_index > 0 {
+            // Only files are moved, and never to the very front
+            if blocks[bwd_index].1.is_none() {
+                bwd_index -= 1;
+                continue;
+            }
+
+            // Look (forward) through blocks to find a swap candidate
+            let mut empty_index = 0;
+            while empty_index < bwd_index && (blocks[empty_index].1.is_some() || blocks[empty_index].0 < blocks[bwd_index].0) {
+                empty_index += 1;
+            }
+
+            // No possible swap
+            if empty_index == bwd_index {
+                bwd_index -= 1;
+                continue;
+            }
+
+            // Partial or full swap
+            if blocks[bwd_index].0 == blocks[empty_index].0 {
+                blocks.swap(empty_index, bwd_index);
+            } else {
+                let new_size = blocks[empty_index].0 - blocks[bwd_index].0;
+                blocks.insert(empty_index, blocks[bwd_index]);
+                blocks[bwd_index + 1].1 = None;
+                blocks[empty_index + 1].0 = new_size;
+            }
+        }
+
+        // Form checksum from blocks
+        let mut index = 0;
+        let mut checksum: u64 = 0;
+        for block in blocks.into_iter() {
+            if let Some(value) = block.1 {
+                for i in 0..block.0 {
+                    checksum += (index + i) as u64 * value as u64;
+                }
+            }
+            index += block.0;
+        }
+
+        checksum.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2333133121414131402";
+
+    #[test]
+    fn part1_example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part1(&input), Answer::Integer(1928));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part2(&input), Answer::Integer(2858));
+    }
+
+    #[test]
+    fn tiny_disks() {
+        for (disk, checksum) in [("0", 0), ("1", 0), ("01", 0), ("12", 0), ("201", 2), ("1011", 1)] {
+            let input = Day09::parse(disk).unwrap();
+            assert_eq!(Day09::part1(&input), Answer::Integer(checksum), "{}", disk);
+            assert_eq!(Day09::part2(&input), Answer::Integer(checksum), "{}", disk);
+        }
+    }
+}
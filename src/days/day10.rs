@@ -0,0 +1,93 @@
+use crate::grid::{Grid, Point};
+use crate::parse::ParseError;
+use crate::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day10;
+
+/// Trail ends (height 9) reachable from a location at the given height.
+pub fn count_paths(map: &Grid<u8>, location: Point, height: u8) -> HashSet<Point> {
+    if height == 9 {
+        let mut paths = HashSet::new();
+        paths.insert(location);
+        return paths;
+    }
+
+    let mut paths = HashSet::new();
+    for new_location in map.neighbours4(location) {
+        if map[new_location] == height + 1 {
+            paths.extend(&count_paths(map, new_location, height + 1));
+        }
+    }
+
+    paths
+}
+
+
+pub fn count_all_paths(map: &Grid<u8>, location: Point, height: u8) -> i32 {
+    if height == 9 {
+        return 1;
+    }
+
+    let mut paths = 0;
+    for new_location in map.neighbours4(location) {
+        if map[new_location] == height + 1 {
+            paths += count_all_paths(map, new_location, height + 1);
+        }
+    }
+
+    paths
+}
+
+
+pub fn find_trailheads(map: &Grid<u8>) -> HashSet<Point> {
+    map.find_all(&0).collect()
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, input, "a height digit",
+                    |c| c.to_digit(10).map(|d| d as u8))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        find_trailheads(map).into_iter()
+            .fold(0, |acc, trailhead| acc + count_paths(map, trailhead, 0).len()).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        find_trailheads(map).into_iter()
+            .fold(0, |acc, trailhead| acc + count_all_paths(map, trailhead, 0)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), Answer::Integer(36));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input), Answer::Integer(81));
+    }
+}
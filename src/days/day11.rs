@@ -0,0 +1,94 @@
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+use std::collections::HashMap;
+
+pub struct Day11;
+
+pub fn num_digits(stone: u64) -> u32 {
+    stone.ilog10() + 1
+}
+
+pub fn apply_rule(stone: u64) -> Vec<u64> {
+    if stone == 0 {
+        vec![1]
+    } else if num_digits(stone).is_multiple_of(2) {
+        let half_digit_factor = 10_u64.pow(num_digits(stone) / 2);
+        vec![stone % half_digit_factor, stone / half_digit_factor]
+    } else {
+        vec![stone * 2024]
+    }
+}
+
+
+pub fn count_stones(stones: &[u64], blink_count: u32) -> u64 {
+    // Histogram of stones
+    let mut stone_hist = HashMap::new();
+    for stone in stones {
+        *stone_hist.entry(*stone).or_insert(0) += 1;
+    }
+
+    for _ in 0..blink_count {
+        let mut new_stone_hist = HashMap::new();
+        for (stone, count) in stone_hist.into_iter() {
+            for new_stone in apply_rule(stone) {
+                *new_stone_hist.entry(new_stone).or_insert(0) += count;
+            }
+        }
+        stone_hist = new_stone_hist;
+    }
+
+    stone_hist.values().sum::<u64>()
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Numbers on stones
+        let mut stones = Vec::new();
+        for line in lines(Self::DAY, input) {
+            stones.extend(line.parse_fields::<u64>()?);
+        }
+
+        Ok(stones)
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        count_stones(stones, 25).into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        count_stones(stones, 75).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+125 17";
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), Answer::Integer(55312));
+    }
+
+    #[test]
+    fn rules() {
+        assert_eq!(apply_rule(0), vec![1]);
+        assert_eq!(apply_rule(1), vec![2024]);
+        assert_eq!(apply_rule(10), vec![0, 1]);
+        assert_eq!(apply_rule(99), vec![9, 9]);
+        assert_eq!(apply_rule(999), vec![2021976]);
+        assert_eq!(apply_rule(1000), vec![0, 10]);
+    }
+
+    #[test]
+    fn blinks() {
+        assert_eq!(count_stones(&[125, 17], 6), 22);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::grid::{Grid, Point};
+use crate::parse::ParseError;
+use crate::{Answer, Solution};
+use petgraph::algo::kosaraju_scc;
+use petgraph::graph::NodeIndex;
+use petgraph::{Graph, Undirected};
+use std::collections::HashSet;
+
+pub struct Day12;
+
+/// Graph with a node for each plot (weighted by its location), and an edge
+/// between neighbouring plots of the same plant.
+pub fn plot_graph(map: &Grid<char>) -> Graph<Point, (), Undirected> {
+    let mut graph = Graph::new_undirected();
+
+    // Node for each element in the graph
+    let mut nodes = Grid::new(map.num_rows(), map.num_cols(), NodeIndex::end());
+    for point in map.points() {
+        nodes[point] = graph.add_node(point);
+    }
+
+    // Add edges to the right and below
+    for (point, plant) in map.iter() {
+        for neighbour in [point + Point::RIGHT, point + Point::DOWN] {
+            if map.get(neighbour) == Some(plant) {
+                graph.add_edge(nodes[point], nodes[neighbour], ());
+            }
+        }
+    }
+
+    graph
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, input, "a plant letter",
+                    |c| c.is_ascii_alphabetic().then_some(c))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        let graph = plot_graph(map);
+
+        // Find connected components
+        let components = kosaraju_scc(&graph);
+
+        // Add up the price
+        let mut price = 0;
+        for component in components {
+            // Area is the number of components
+            let area = component.len();
+
+            // The perimeter of each plot is just 4 minus the number of edges
+            let perimeter = component.iter()
+                .map(|index| 4 - graph.edges(*index).count())
+                .sum::<usize>();
+
+            price += area * perimeter;
+        }
+
+        price.into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let graph = plot_graph(map);
+
+        // Find connected components
+        let components = kosaraju_scc(&graph);
+
+        let corners = [
+            (Point::UP, Point::LEFT, Point::new(-1, -1)),
+            (Point::UP, Point::RIGHT, Point::new(-1, 1)),
+            (Point::DOWN, Point::LEFT, Point::new(1, -1)),
+            (Point::DOWN, Point::RIGHT, Point::new(1, 1)),
+        ];
+
+        // Add up the price
+        let mut price = 0;
+        for component in components {
+            // Area is the number of components
+            let area = component.len();
+
+            // Grid locations in this component
+            let locations = component.iter()
+                .map(|index| *graph.node_weight(*index).unwrap())
+                .collect::<HashSet<_>>();
+
+            // Perimeter is the number of sides (= number of corners)
+            let mut corner_count = 0;
+            for location in locations.iter() {
+                for corner in corners.iter() {
+                    // Outside corner
+                    if !locations.contains(&(*location + corner.0)) &&
+                        !locations.contains(&(*location + corner.1)) {
+                        corner_count += 1;
+                    }
+
+                    // Inside corner
+                    if locations.contains(&(*location + corner.0)) &&
+                        locations.contains(&(*location + corner.1)) &&
+                        !locations.contains(&(*location + corner.2)) {
+                        corner_count += 1;
+                    }
+                }
+            }
+            price += area * corner_count;
+        }
+
+        price.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+    const EXAMPLE_2: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day12::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day12::part1(&input), Answer::Integer(140));
+    }
+
+    #[test]
+    fn part2_small_example() {
+        let input = Day12::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day12::part2(&input), Answer::Integer(80));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day12::part1(&input), Answer::Integer(1930));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day12::part2(&input), Answer::Integer(1206));
+    }
+}
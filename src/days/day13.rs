@@ -0,0 +1,132 @@
+use crate::parse::{lines, Line, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day13;
+
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub button_a: [i64; 2],
+    pub button_b: [i64; 2],
+    pub prize: [i64; 2],
+}
+
+
+pub fn count_tokens(problems: &[Problem], factor: i64) -> i64 {
+    let mut cost = 0;
+    for problem in problems {
+
+        // Calculate determinant (ax * by - ay * bx)
+        let determinant = problem.button_a[0] * problem.button_b[1] -
+            problem.button_a[1] * problem.button_b[0];
+
+        // Calculate partial solution
+        // (by * x - bx * y)
+        let a_factor = problem.button_b[1] * (factor + problem.prize[0]) -
+            problem.button_b[0] * (factor + problem.prize[1]);
+        // (-ay * x + ax * y)
+        let b_factor = -problem.button_a[1] * (factor + problem.prize[0]) +
+            problem.button_a[0] * (factor + problem.prize[1]);
+
+        // Check partial solution is (integer) divisible by the determinant
+        if (a_factor % determinant == 0) && (b_factor % determinant == 0) {
+            cost += 3 * a_factor / determinant + b_factor / determinant;
+        }
+    }
+
+    cost
+}
+
+
+/// Parse `X+94, Y+34` (with `separator` `+`) or `X=8400, Y=5400` (with `=`).
+pub fn get_values(line: &Line, s: &str, separator: &str) -> Result<[i64; 2], ParseError> {
+    let (x, y) = line.split_once(s, ", ")?;
+    let x = line.strip_prefix(x, &format!("X{}", separator))?;
+    let y = line.strip_prefix(y, &format!("Y{}", separator))?;
+
+    Ok([line.parse(x)?, line.parse(y)?])
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Problem>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(Self::DAY, input)
+            .filter(|line| !line.text.is_empty());
+        let mut next_line = |name: &str| lines.next()
+            .ok_or_else(|| ParseError::end_of_input(Self::DAY, input, format!("expected `{}` line", name)));
+
+        let mut problems = Vec::new();
+        while let Ok(line_a) = next_line("Button A") {
+            let line_b = next_line("Button B")?;
+            let line_prize = next_line("Prize")?;
+
+            let button_a = get_values(&line_a, line_a.strip_prefix(line_a.text, "Button A: ")?, "+")?;
+            let button_b = get_values(&line_b, line_b.strip_prefix(line_b.text, "Button B: ")?, "+")?;
+
+            // The presses are only unique (and solved for) when the buttons are independent
+            if button_a[0] * button_b[1] == button_a[1] * button_b[0] {
+                return Err(line_b.error_at(line_b.text, "expected button B to move in a different direction from button A"));
+            }
+
+            problems.push(Problem {
+                button_a,
+                button_b,
+                prize: get_values(&line_prize, line_prize.strip_prefix(line_prize.text, "Prize: ")?, "=")?,
+            });
+        }
+
+        Ok(problems)
+    }
+
+    fn part1(problems: &Self::Input) -> Answer {
+        count_tokens(problems, 0).into()
+    }
+
+    fn part2(problems: &Self::Input) -> Answer {
+        count_tokens(problems, 10000000000000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), Answer::Integer(480));
+    }
+
+    #[test]
+    fn parse_values() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(input[0].button_a, [94, 34]);
+        assert_eq!(input[0].button_b, [22, 67]);
+        assert_eq!(input[0].prize, [8400, 5400]);
+    }
+
+    #[test]
+    fn parallel_buttons() {
+        let error = Day13::parse("Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=3, Y=3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
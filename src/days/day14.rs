@@ -0,0 +1,216 @@
+use crate::grid::{Grid, Point};
+use crate::parse::{lines, Line, ParseError};
+use crate::render::{Cell, Colour, Frames, Picture};
+use crate::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day14;
+
+/// Size of the area the robots patrol.
+pub const X_WIDTH: i64 = 101;
+pub const Y_WIDTH: i64 = 103;
+
+/// Number of frames recorded either side of the tree.
+pub const TREE_FRAMES: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct State {
+    pub pos: [i64; 2],
+    pub vel: [i64; 2],
+}
+
+impl State {
+    pub fn predict_position(&self, time: i64, x_width: i64, y_width: i64) -> [i64; 2] {
+        let new_x = i64_mod(self.pos[0] + time * self.vel[0], x_width);
+        let new_y = i64_mod(self.pos[1] + time * self.vel[1], y_width);
+
+        [new_x, new_y]
+    }
+}
+
+pub fn i64_mod(x: i64, v: i64) -> i64 {
+    ((x % v) + v) % v
+}
+
+pub fn safety_factor(robots: &[State], steps: i64, x_width: i64, y_width: i64) -> u64 {
+    let mut new_locations = Vec::new();
+    for robot in robots {
+        let new_pos = robot.predict_position(steps, x_width, y_width);
+        new_locations.push(new_pos);
+    }
+
+    let mut counts = [0_u64; 4];
+    for location in new_locations {
+        if location[0] < x_width / 2 && location[1] < y_width / 2 {
+            counts[0] += 1;
+        } else if location[0] < x_width / 2 && location[1] > y_width / 2 {
+            counts[1] += 1;
+        } else if location[0] > x_width / 2 && location[1] < y_width / 2 {
+            counts[2] += 1;
+        } else if location[0] > x_width / 2 && location[1] > y_width / 2 {
+            counts[3] += 1;
+        }
+    }
+
+    counts[0] * counts[1] * counts[2] * counts[3]
+}
+
+
+pub fn find_tree(robots: &[State], x_width: i64, y_width: i64) -> i64 {
+    let mut new_locations = HashSet::new();
+    let mut steps = 0;
+    loop {
+        new_locations.clear();
+        for robot in robots {
+            let new_pos = robot.predict_position(steps, x_width, y_width);
+            if new_locations.contains(&new_pos) {
+                break;
+            }
+            new_locations.insert(new_pos);
+        }
+
+        // Check for robots in distinct locations
+        if new_locations.len() == robots.len() {
+            break;
+        }
+        steps += 1;
+    }
+
+    steps
+}
+
+
+/// Robots after some time, each tile showing how many are on it.
+pub fn picture(robots: &[State], time: i64, x_width: i64, y_width: i64) -> Picture {
+    let mut counts = Grid::new(y_width as usize, x_width as usize, 0_u32);
+    for robot in robots {
+        let [x, y] = robot.predict_position(time, x_width, y_width);
+        counts[Point::new(y as i32, x as i32)] += 1;
+    }
+
+    Picture::new(&counts, |&count| match count {
+        0 => Cell::new('.', Colour::DARK_GREY),
+        _ => Cell::new(char::from_digit(count.min(9), 10).unwrap(), Colour::GREEN),
+    })
+}
+
+
+pub fn get_values(line: &Line, s: &str) -> Result<[i64; 2], ParseError> {
+    let (x, y) = line.split_once(s, ",")?;
+
+    Ok([line.parse(x)?, line.parse(y)?])
+}
+
+/// Pictures of the steps around the tree, sampled so that the tree itself is included.
+pub fn tree_frames(robots: &[State], every: usize) -> Frames<'_> {
+    let tree = find_tree(robots, X_WIDTH, Y_WIDTH) as usize;
+    let first = tree - TREE_FRAMES.min(tree / every) * every;
+    let last = tree + TREE_FRAMES * every;
+
+    Box::new((first..=last).step_by(every)
+        .map(|step| (step, picture(robots, step as i64, X_WIDTH, Y_WIDTH))))
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<State>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut robots = Vec::new();
+        for line in lines(Self::DAY, input) {
+            let (pos, vel) = line.split_once(line.text, " ")?;
+            robots.push(State {
+                pos: get_values(&line, line.strip_prefix(pos, "p=")?)?,
+                vel: get_values(&line, line.strip_prefix(vel, "v=")?)?,
+            });
+        }
+
+        Ok(robots)
+    }
+
+    fn part1(robots: &Self::Input) -> Answer {
+        safety_factor(robots, 100, X_WIDTH, Y_WIDTH).into()
+    }
+
+    fn part2(robots: &Self::Input) -> Answer {
+        find_tree(robots, X_WIDTH, Y_WIDTH).into()
+    }
+
+    fn frames(robots: &Self::Input, every: usize) -> Option<Frames<'_>> {
+        // Every step until the robots are back where they started
+        let period = (X_WIDTH * Y_WIDTH) as usize;
+
+        Some(Box::new((0..period).step_by(every)
+            .map(|step| (step, picture(robots, step as i64, X_WIDTH, Y_WIDTH)))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn safety_factor_example() {
+        let robots = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(safety_factor(&robots, 100, 11, 7), 12);
+    }
+
+    #[test]
+    fn predict() {
+        let robot = State { pos: [2, 4], vel: [2, -3] };
+        assert_eq!(robot.predict_position(5, 11, 7), [1, 3]);
+    }
+
+    #[test]
+    fn picture_example() {
+        let robots = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(picture(&robots, 100, 11, 7).to_text(), "\
+......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1....
+");
+    }
+
+    #[test]
+    fn frames_around_tree() {
+        let robots = Day14::parse(EXAMPLE).unwrap();
+        let tree = find_tree(&robots, X_WIDTH, Y_WIDTH) as usize;
+        let steps = tree_frames(&robots, 3)
+            .map(|(step, _)| step)
+            .collect::<Vec<_>>();
+
+        assert!(steps.contains(&tree));
+        assert_eq!(steps.last(), Some(&(tree + 15)));
+        assert!(steps.windows(2).all(|pair| pair[1] - pair[0] == 3));
+    }
+
+    #[test]
+    fn frames_from_start() {
+        let robots = Day14::parse(EXAMPLE).unwrap();
+        let mut frames = Day14::frames(&robots, 1000).unwrap();
+
+        let (step, first) = frames.next().unwrap();
+        assert_eq!(step, 0);
+        assert_eq!(first.to_text(), picture(&robots, 0, X_WIDTH, Y_WIDTH).to_text());
+        assert_eq!(frames.map(|(step, _)| step).last(), Some(10000));
+    }
+}
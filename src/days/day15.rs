@@ -0,0 +1,277 @@
+use crate::grid::{Direction, Grid, Point};
+use crate::parse::{check_border, check_single, lines, parse_rows, ParseError};
+use crate::render::{Cell, Colour, Frames, Picture};
+use crate::{Answer, Solution};
+use std::cmp::PartialEq;
+use std::collections::HashSet;
+use std::fmt;
+use std::iter;
+
+pub struct Day15;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapContent {
+    Wall,
+    Box,
+    /// Halves of a box in the widened warehouse.
+    BoxLeft,
+    BoxRight,
+    Robot,
+    Empty,
+}
+
+impl fmt::Display for MapContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapContent::Wall => write!(f, "#"),
+            MapContent::Box => write!(f, "O"),
+            MapContent::BoxLeft => write!(f, "["),
+            MapContent::BoxRight => write!(f, "]"),
+            MapContent::Robot => write!(f, "@"),
+            MapContent::Empty => write!(f, "."),
+        }
+    }
+}
+
+
+pub fn picture(map: &Grid<MapContent>) -> Picture {
+    Picture::new(map, |content| match content {
+        MapContent::Wall => Cell::new('#', Colour::GREY),
+        MapContent::Box => Cell::new('O', Colour::ORANGE),
+        MapContent::BoxLeft => Cell::new('[', Colour::ORANGE),
+        MapContent::BoxRight => Cell::new(']', Colour::ORANGE),
+        MapContent::Robot => Cell::new('@', Colour::YELLOW),
+        MapContent::Empty => Cell::new('.', Colour::DARK_GREY),
+    })
+}
+
+
+pub fn move_robot(map: &mut Grid<MapContent>,
+                  coord: Point,
+                  direction: Direction) -> Point {
+    let dirn = direction.offset();
+
+    // Everything pushed along by the robot, which for wide boxes
+    // moving up or down can spread out into a tree of boxes
+    let mut moving = vec![coord];
+    let mut seen = HashSet::from([coord]);
+    let mut idx = 0;
+    while idx < moving.len() {
+        let next = moving[idx] + dirn;
+        idx += 1;
+
+        let pushed = match map[next] {
+            MapContent::Wall => return coord,
+            MapContent::Box => vec![next],
+            MapContent::BoxLeft => vec![next, next + Point::RIGHT],
+            MapContent::BoxRight => vec![next, next + Point::LEFT],
+            MapContent::Robot | MapContent::Empty => vec![],
+        };
+        for cell in pushed {
+            if seen.insert(cell) {
+                moving.push(cell);
+            }
+        }
+    }
+
+    // Nothing is blocked, so move from the far end back to the robot
+    for &cell in moving.iter().rev() {
+        map[cell + dirn] = map[cell].clone();
+        map[cell] = MapContent::Empty;
+    }
+
+    coord + dirn
+}
+
+/// Warehouse twice as wide, with each box taking up two tiles.
+pub fn widen(map: &Grid<MapContent>) -> Grid<MapContent> {
+    let rows = map.rows()
+        .map(|row| row.iter()
+            .flat_map(|content| match content {
+                MapContent::Wall => [MapContent::Wall, MapContent::Wall],
+                MapContent::Box => [MapContent::BoxLeft, MapContent::BoxRight],
+                MapContent::Robot => [MapContent::Robot, MapContent::Empty],
+                _ => [content.clone(), content.clone()],
+            })
+            .collect())
+        .collect();
+
+    Grid::from_rows(rows).expect("rows have equal length")
+}
+
+/// Sum of the GPS coordinates of the boxes, measured to their left edge.
+pub fn gps_sum(map: &Grid<MapContent>) -> i32 {
+    map.iter()
+        .filter(|(_, content)| matches!(content, MapContent::Box | MapContent::BoxLeft))
+        .map(|(coord, _)| 100 * coord.row + coord.col)
+        .sum()
+}
+
+/// Warehouse after the robot has made all its moves.
+pub fn run_moves(map: &Grid<MapContent>, moves: &[Direction]) -> Grid<MapContent> {
+    let mut mut_map = map.clone();
+    let mut coord = map.find(&MapContent::Robot).expect("map has a robot");
+
+    for m in moves {
+        coord = move_robot(&mut mut_map, coord, *m);
+    }
+
+    mut_map
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (Grid<MapContent>, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(Self::DAY, input);
+
+        // Map up to the first blank line
+        let map_vec = parse_rows(lines.by_ref().take_while(|line| !line.text.is_empty()),
+                                 "`#`, `O`, `@` or `.`",
+                                 |c| match c {
+                                     '#' => Some(MapContent::Wall),
+                                     'O' => Some(MapContent::Box),
+                                     '@' => Some(MapContent::Robot),
+                                     '.' => Some(MapContent::Empty),
+                                     _ => None,
+                                 })?;
+        if map_vec.is_empty() {
+            return Err(ParseError::end_of_input(Self::DAY, input, "expected a map"));
+        }
+        let map = Grid::from_rows(map_vec).expect("rows have equal length");
+        check_border(Self::DAY, &map, |val| *val == MapContent::Wall)?;
+        check_single(Self::DAY, input, &map, &MapContent::Robot, "robot `@`")?;
+
+        // Then the moves
+        let mut moves = Vec::new();
+        for line in lines {
+            let line_moves = line.map_chars("`<`, `>`, `^` or `v`", Direction::from_arrow)?;
+
+            moves.extend(line_moves);
+        }
+
+        Ok((map, moves))
+    }
+
+    fn part1((map, moves): &Self::Input) -> Answer {
+        gps_sum(&run_moves(map, moves)).into()
+    }
+
+    fn part2((map, moves): &Self::Input) -> Answer {
+        gps_sum(&run_moves(&widen(map), moves)).into()
+    }
+
+    fn render((map, moves): &Self::Input) -> Option<Picture> {
+        Some(picture(&run_moves(map, moves)))
+    }
+
+    fn frames((map, moves): &Self::Input, every: usize) -> Option<Frames<'_>> {
+        // The warehouse before any moves, then after every `every`th move
+        let mut mut_map = map.clone();
+        let mut coord = map.find(&MapContent::Robot).expect("map has a robot");
+        let first = (0, picture(map));
+
+        Some(Box::new(iter::once(first).chain(moves.iter().enumerate()
+            .filter_map(move |(i, m)| {
+                coord = move_robot(&mut mut_map, coord, *m);
+                ((i + 1) % every == 0).then(|| (i + 1, picture(&mut_map)))
+            }))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+    const EXAMPLE_2: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    const EXAMPLE_3: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day15::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day15::part1(&input), Answer::Integer(2028));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day15::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day15::part1(&input), Answer::Integer(10092));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day15::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day15::part2(&input), Answer::Integer(9021));
+    }
+
+    #[test]
+    fn wide_pushes() {
+        let input = Day15::parse(EXAMPLE_3).unwrap();
+        let map = widen(&input.0);
+        assert_eq!(map.to_string().lines().nth(3), Some("##....[][]@.##"));
+
+        let map = run_moves(&map, &input.1);
+        assert_eq!(map.to_string(), "\
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############
+");
+    }
+
+    #[test]
+    fn frames_example() {
+        let input = Day15::parse(EXAMPLE_1).unwrap();
+        let frames = Day15::frames(&input, 5).unwrap().collect::<Vec<_>>();
+        assert_eq!(frames.iter().map(|(step, _)| *step).collect::<Vec<_>>(), vec![0, 5, 10, 15]);
+        assert_eq!(frames[3].1, picture(&run_moves(&input.0, &input.1)));
+        assert_eq!(frames[0].1.to_text().lines().nth(2), Some("##@.O..#"));
+    }
+}
@@ -0,0 +1,273 @@
+use crate::grid::{Direction, Grid, Point};
+use crate::parse::{check_border, check_single, ParseError};
+use crate::render::{Cell, Colour, Picture};
+use crate::{Answer, Solution};
+use crate::search::{dijkstra, SearchResult};
+use std::collections::HashSet;
+use std::fmt;
+
+pub struct Day16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapContent {
+    Wall,
+    Empty,
+    Start,
+    End,
+}
+
+impl fmt::Display for MapContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapContent::Wall => write!(f, "#"),
+            MapContent::Start => write!(f, "S"),
+            MapContent::End => write!(f, "E"),
+            MapContent::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct State {
+    pub location: Point,
+    pub direction: Direction,
+    pub last_move_was_turn: bool,
+}
+
+pub fn picture(map: &Grid<MapContent>) -> Picture {
+    Picture::new(map, |content| match content {
+        MapContent::Wall => Cell::new('#', Colour::GREY),
+        MapContent::Empty => Cell::new('.', Colour::DARK_GREY),
+        MapContent::Start => Cell::new('S', Colour::YELLOW),
+        MapContent::End => Cell::new('E', Colour::YELLOW),
+    })
+}
+
+pub fn find_start(map: &Grid<MapContent>) -> Point {
+    map.find(&MapContent::Start).expect("map has a start")
+}
+
+
+/// Move of the reindeer: a step forward, or a turn on the spot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Forward,
+    TurnLeft,
+    TurnRight,
+}
+
+impl Move {
+    pub const ALL: [Move; 3] = [Move::Forward, Move::TurnLeft, Move::TurnRight];
+
+    pub fn cost(&self) -> u64 {
+        match self {
+            Move::Forward => 1,
+            Move::TurnLeft | Move::TurnRight => 1000,
+        }
+    }
+
+    /// State after the move, `None` if it walks into a wall.
+    pub fn apply(&self, map: &Grid<MapContent>, state: &State) -> Option<State> {
+        let (location, direction) = match self {
+            Move::Forward => (state.location + state.direction.offset(), state.direction),
+            Move::TurnLeft => (state.location, state.direction.turn_left()),
+            Move::TurnRight => (state.location, state.direction.turn_right()),
+        };
+        if map[location] == MapContent::Wall {
+            return None;
+        }
+
+        Some(State { location, direction, last_move_was_turn: *self != Move::Forward })
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Move::Forward => write!(f, "F"),
+            Move::TurnLeft => write!(f, "L"),
+            Move::TurnRight => write!(f, "R"),
+        }
+    }
+}
+
+/// States reachable from a state, with the cost of getting there.
+pub fn moves(map: &Grid<MapContent>, state: &State) -> Vec<(State, u64)> {
+    Move::ALL.iter()
+        // Turning twice in a row is never cheaper than walking
+        .filter(|&&m| m == Move::Forward || !state.last_move_was_turn)
+        .filter_map(|m| m.apply(map, state).map(|next| (next, m.cost())))
+        .collect()
+}
+
+/// Search for the cheapest ways from the start to the end.
+pub fn explore(map: &Grid<MapContent>) -> SearchResult<State, u64> {
+    dijkstra([start_state(map)],
+             |state| moves(map, state),
+             |state| map[state.location] == MapContent::End)
+}
+
+/// One lowest-score route from the start to the end, as the moves made.
+pub fn best_route(map: &Grid<MapContent>) -> Option<Vec<Move>> {
+    let path = explore(map).path()?;
+
+    Some(path.windows(2)
+        .map(|pair| *Move::ALL.iter()
+            .find(|m| m.apply(map, &pair[0]).as_ref() == Some(&pair[1]))
+            .expect("consecutive states are one move apart"))
+        .collect())
+}
+
+pub fn start_state(map: &Grid<MapContent>) -> State {
+    State {
+        location: find_start(map),
+        direction: Direction::East,
+        last_move_was_turn: false,
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Grid<MapContent>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(Self::DAY, input, "`#`, `S`, `E` or `.`", |c| match c {
+            '#' => Some(MapContent::Wall),
+            'S' => Some(MapContent::Start),
+            'E' => Some(MapContent::End),
+            '.' => Some(MapContent::Empty),
+            _ => None,
+        })?;
+        check_border(Self::DAY, &map, |val| *val == MapContent::Wall)?;
+        check_single(Self::DAY, input, &map, &MapContent::Start, "start `S`")?;
+        check_single(Self::DAY, input, &map, &MapContent::End, "end `E`")?;
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        explore(map).cost().map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let result = explore(map);
+        if result.cost().is_none() {
+            return Answer::Unsolved;
+        }
+
+        result.optimal_states().iter()
+            .map(|state| state.location)
+            .collect::<HashSet<_>>()
+            .len().into()
+    }
+
+    fn render(map: &Self::Input) -> Option<Picture> {
+        // Tiles on any best path, with one of them drawn on top
+        let result = explore(map);
+        let mut route = result.path()?.into_iter()
+            .map(|state| state.location)
+            .collect::<Vec<_>>();
+        route.dedup();
+
+        let mut picture = picture(map);
+        picture.overlay(result.optimal_states().iter()
+                            .map(|state| state.location)
+                            .filter(|&location| map[location] == MapContent::Empty),
+                        Cell::new('O', Colour::GREEN))
+            .path(&route, Colour::RED);
+
+        Some(picture)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+    const EXAMPLE_2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn part1_example() {
+        let input = Day16::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day16::part1(&input), Answer::Integer(7036));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day16::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day16::part2(&input), Answer::Integer(45));
+    }
+
+    #[test]
+    fn walled_off_end() {
+        let input = Day16::parse("\
+######
+#S.#E#
+######").unwrap();
+        assert_eq!(Day16::part1(&input), Answer::Unsolved);
+        assert_eq!(Day16::part2(&input), Answer::Unsolved);
+    }
+
+    #[test]
+    fn best_route_example() {
+        let input = Day16::parse(EXAMPLE_1).unwrap();
+        let route = best_route(&input).unwrap();
+        assert_eq!(route.iter().map(Move::cost).sum::<u64>(), 7036);
+        assert_eq!(route.iter().filter(|&&m| m != Move::Forward).count(), 7);
+        assert_eq!(route.first(), Some(&Move::TurnLeft));
+    }
+
+    #[test]
+    fn render_best_tiles() {
+        let input = Day16::parse(EXAMPLE_1).unwrap();
+        let picture = Day16::render(&input).unwrap().to_text();
+        let tiles = picture.chars().filter(|c| "O^v<>SE".contains(*c)).count();
+        assert_eq!(tiles, 45);
+    }
+
+    #[test]
+    fn part1_second_example() {
+        let input = Day16::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day16::part1(&input), Answer::Integer(11048));
+    }
+
+    #[test]
+    fn part2_second_example() {
+        let input = Day16::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day16::part2(&input), Answer::Integer(64));
+    }
+}
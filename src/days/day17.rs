@@ -0,0 +1,1247 @@
+use crate::parse::{lines, Line, ParseError};
+use crate::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write};
+use std::mem;
+
+pub struct Day17;
+
+/// Instructions a quine candidate may run before it is taken not to halt.
+pub const QUINE_MAX_STEPS: usize = 100_000;
+
+/// Instructions the bit-constraint search may explore, over all its branches.
+pub const CONSTRAINT_BUDGET: usize = 1_000_000;
+
+/// Most unknown bits in a shift amount that the bit-constraint search branches over.
+pub const MAX_BRANCH_BITS: usize = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    pub register_a: u64,
+    pub register_b: u64,
+    pub register_c: u64,
+    pub instructions: Vec<[u8; 2]>,
+}
+
+/// Program in the puzzle input format.
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Register A: {}", self.register_a)?;
+        writeln!(f, "Register B: {}", self.register_b)?;
+        writeln!(f, "Register C: {}", self.register_c)?;
+        writeln!(f)?;
+
+        let values = self.instructions.iter()
+            .flatten()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        writeln!(f, "Program: {}", values.join(","))
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    /// Opcodes in the order of their 3-bit codes.
+    pub const ALL: [Opcode; 8] = [Opcode::Adv, Opcode::Bxl, Opcode::Bst, Opcode::Jnz,
+                                  Opcode::Bxc, Opcode::Out, Opcode::Bdv, Opcode::Cdv];
+
+    pub fn from_code(code: u8) -> Self {
+        Opcode::ALL[code as usize]
+    }
+
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Opcode::ALL.into_iter().find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+
+    /// Whether the operand is a combo operand rather than a literal.
+    pub fn takes_combo(&self) -> bool {
+        matches!(self, Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv)
+    }
+}
+
+/// Combo operand as written: a literal up to 3, or the register it reads.
+pub fn combo_name(operand: u8) -> String {
+    match operand {
+        0..4 => operand.to_string(),
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        _ => format!("<invalid {}>", operand),
+    }
+}
+
+/// Operand of an instruction as written, empty when it is ignored.
+fn operand_name(opcode: Opcode, operand: u8) -> String {
+    match opcode {
+        Opcode::Bxc => String::new(),
+        _ if opcode.takes_combo() => combo_name(operand),
+        _ => operand.to_string(),
+    }
+}
+
+/// Instructions as mnemonics, one per line after their address.
+pub fn disassemble(instructions: &[[u8; 2]]) -> String {
+    let width = (2 * instructions.len()).saturating_sub(2).to_string().len();
+
+    let mut text = String::new();
+    for (i, &[code, operand]) in instructions.iter().enumerate() {
+        let opcode = Opcode::from_code(code);
+        let line = format!("{:>width$}: {} {}", 2 * i, opcode.mnemonic(), operand_name(opcode, operand));
+        writeln!(text, "{}", line.trim_end()).unwrap();
+    }
+
+    text
+}
+
+/// Instruction as a statement on the registers, in a program of `len` instructions.
+fn statement(opcode: Opcode, operand: u8, len: usize) -> String {
+    let combo = combo_name(operand);
+    match opcode {
+        Opcode::Adv => format!("A = A >> {}", combo),
+        Opcode::Bxl => format!("B = B ^ {}", operand),
+        Opcode::Bst if operand < 4 => format!("B = {}", operand),
+        Opcode::Bst => format!("B = {} % 8", combo),
+        Opcode::Jnz if operand as usize >= 2 * len => "if A != 0 halt".to_string(),
+        Opcode::Jnz if operand % 2 == 1 => format!("if A != 0 goto {} (mid-instruction)", operand),
+        Opcode::Jnz => format!("if A != 0 goto L{}", operand),
+        Opcode::Bxc => "B = B ^ C".to_string(),
+        Opcode::Out if operand < 4 => format!("out {}", operand),
+        Opcode::Out => format!("out {} % 8", combo),
+        Opcode::Bdv => format!("B = A >> {}", combo),
+        Opcode::Cdv => format!("C = A >> {}", combo),
+    }
+}
+
+/// Program as pseudo-code, with each jump back to an earlier instruction
+/// written as a loop around it, and any other jumps to labels.
+pub fn pseudo_code(instructions: &[[u8; 2]]) -> String {
+    // Backward jumps as loops from the target to the jump, keeping only
+    // those that nest inside each other, outermost first
+    let mut loops = instructions.iter().enumerate()
+        .filter(|(_, &[code, target])| Opcode::from_code(code) == Opcode::Jnz && target % 2 == 0)
+        .map(|(i, &[_, target])| (target as usize / 2, i))
+        .filter(|&(start, end)| start <= end)
+        .collect::<Vec<_>>();
+    loops.sort_by_key(|&(start, end)| (start, usize::MAX - end));
+    let mut nested: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in loops {
+        if nested.iter().all(|&(s, e)| end < s || start > e || (s <= start && end < e)) {
+            nested.push((start, end));
+        }
+    }
+
+    // Labels for the other jumps that land on an instruction
+    let labels = instructions.iter().enumerate()
+        .filter(|(i, &[code, target])| Opcode::from_code(code) == Opcode::Jnz &&
+            target % 2 == 0 && !nested.contains(&(target as usize / 2, *i)))
+        .map(|(_, &[_, target])| target as usize / 2)
+        .filter(|&target| target < instructions.len())
+        .collect::<HashSet<_>>();
+
+    let mut text = String::new();
+    let mut depth = 0;
+    for (i, &[code, operand]) in instructions.iter().enumerate() {
+        if labels.contains(&i) {
+            writeln!(text, "L{}:", 2 * i).unwrap();
+        }
+        for _ in nested.iter().filter(|&&(start, _)| start == i) {
+            writeln!(text, "{}do {{", "    ".repeat(depth)).unwrap();
+            depth += 1;
+        }
+
+        // Each jump closes at most one loop, as it only has one target
+        if nested.iter().any(|&(_, end)| end == i) {
+            depth -= 1;
+            writeln!(text, "{}}} while A != 0", "    ".repeat(depth)).unwrap();
+        } else {
+            writeln!(text, "{}{}", "    ".repeat(depth), statement(Opcode::from_code(code), operand, instructions.len())).unwrap();
+        }
+    }
+
+    text
+}
+
+/// Encode the operand of an instruction, with jump targets given as labels or addresses.
+fn assemble_operand(line: &Line,
+                    opcode: Opcode,
+                    operand: Option<&str>,
+                    labels: &HashMap<&str, usize>) -> Result<u8, ParseError> {
+    let Some(operand) = operand else {
+        return match opcode {
+            Opcode::Bxc => Ok(0),
+            _ => Err(line.error_at(line.text, format!("`{}` needs an operand", opcode.mnemonic()))),
+        };
+    };
+
+    let value = match (opcode, operand) {
+        (_, "A" | "B" | "C") if opcode.takes_combo() => return Ok(4 + (operand.as_bytes()[0] - b'A')),
+        (_, "A" | "B" | "C") => {
+            return Err(line.error_at(operand, format!("`{}` takes a literal operand, not a register", opcode.mnemonic())));
+        }
+        (Opcode::Jnz, _) if !operand.starts_with(|c: char| c.is_ascii_digit()) => {
+            let address = *labels.get(operand)
+                .ok_or_else(|| line.error_at(operand, format!("unknown label `{}`", operand)))?;
+            if address > 7 {
+                return Err(line.error_at(operand, format!("label `{}` at {} is beyond a 3-bit jump", operand, address)));
+            }
+            address as u8
+        }
+        _ => line.parse::<u8>(operand)?,
+    };
+
+    match value {
+        7 if opcode.takes_combo() => Err(line.error_at(operand, "combo operand 7 is reserved")),
+        4..7 if opcode.takes_combo() => Err(line.error_at(operand, "expected a literal up to 3, or `A`, `B` or `C`")),
+        8.. => Err(line.error_at(operand, "expected a 3-bit operand")),
+        _ => Ok(value),
+    }
+}
+
+/// Program from assembly source.
+///
+/// Each line holds an instruction as a mnemonic and operand, with combo
+/// operands written as a literal up to 3 or a register `A`, `B` or `C`.
+/// Lines may start with a `label:` to jump to, registers are set with lines
+/// like `A = 729`, and `;` starts a comment.
+pub fn assemble(source: &str) -> Result<Program, ParseError> {
+    let mut registers = [0_u64; 3];
+    let mut statements = Vec::new();
+    let mut labels = HashMap::new();
+
+    // Registers, labels and instructions, leaving the operands until every label is known
+    for line in lines(Day17::DAY, source) {
+        let mut text = line.text.split(';').next().unwrap().trim();
+        if let Some((register, value)) = text.split_once('=') {
+            let register = register.trim();
+            let index = ["A", "B", "C"].iter().position(|&name| name == register)
+                .ok_or_else(|| line.error_at(register, "expected register `A`, `B` or `C`"))?;
+            registers[index] = line.parse(value.trim())?;
+            continue;
+        }
+
+        if let Some((label, rest)) = text.split_once(':') {
+            let label = label.trim();
+            if label.is_empty() || !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') ||
+                label.starts_with(|c: char| c.is_ascii_digit()) || ["A", "B", "C"].contains(&label) {
+                return Err(line.error_at(label, "expected a label name"));
+            }
+            if labels.insert(label, 2 * statements.len()).is_some() {
+                return Err(line.error_at(label, format!("label `{}` is defined twice", label)));
+            }
+            text = rest.trim();
+        }
+
+        let mut fields = text.split_ascii_whitespace();
+        let Some(mnemonic) = fields.next() else {
+            continue;
+        };
+        let opcode = Opcode::from_mnemonic(mnemonic)
+            .ok_or_else(|| line.error_at(mnemonic, format!("unknown instruction `{}`", mnemonic)))?;
+        let operand = fields.next();
+        if let Some(extra) = fields.next() {
+            return Err(line.error_at(extra, "expected one operand"));
+        }
+
+        statements.push((line, opcode, operand));
+    }
+
+    let instructions = statements.iter()
+        .map(|(line, opcode, operand)| Ok([opcode.code(), assemble_operand(line, *opcode, *operand, &labels)?]))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let [register_a, register_b, register_c] = registers;
+    Ok(Program { register_a, register_b, register_c, instructions })
+}
+
+
+/// Run a program until it halts, giving the final registers and the output,
+/// or how it stopped instead.
+pub fn run_program(register_a: u64,
+                   register_b: u64,
+                   register_c: u64,
+                   instructions: &[[u8; 2]]) -> Result<(u64, u64, u64, Vec<u8>), Stop> {
+    let mut registers = Registers { a: register_a, b: register_b, c: register_c };
+    let mut output = Vec::new();
+    match Compiled::new(instructions).run(&mut registers, &mut output, usize::MAX) {
+        Stop::Halted => Ok((registers.a, registers.b, registers.c, output)),
+        stop => Err(stop),
+    }
+}
+
+/// `value` divided by two to the power of `amount`, which is zero for shifts past every bit.
+pub fn shift_right(value: u64, amount: u64) -> u64 {
+    u32::try_from(amount).ok()
+        .and_then(|amount| value.checked_shr(amount))
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Registers {
+    /// Value of a combo operand, or `None` for the reserved 7.
+    pub fn combo(&self, operand: u8) -> Option<u64> {
+        match operand {
+            0..4 => Some(operand as u64),
+            4 => Some(self.a),
+            5 => Some(self.b),
+            6 => Some(self.c),
+            _ => None,
+        }
+    }
+
+    /// Run the instruction at `pointer`, giving the pointer of the next one and
+    /// any output, or `None` if it has an invalid combo operand.
+    pub fn execute(&mut self, opcode: Opcode, operand: u8, pointer: usize) -> Option<(usize, Option<u8>)> {
+        let combo = || self.combo(operand);
+        match opcode {
+            Opcode::Adv => self.a = shift_right(self.a, combo()?),
+            Opcode::Bxl => self.b ^= operand as u64,
+            Opcode::Bst => self.b = combo()? % 8,
+            Opcode::Jnz if self.a != 0 => return Some((operand as usize, None)),
+            Opcode::Jnz => (),
+            Opcode::Bxc => self.b ^= self.c,
+            Opcode::Out => return Some((pointer + 2, Some((combo()? % 8) as u8))),
+            Opcode::Bdv => self.b = shift_right(self.a, combo()?),
+            Opcode::Cdv => self.c = shift_right(self.a, combo()?),
+        }
+
+        Some((pointer + 2, None))
+    }
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A={} B={} C={}", self.a, self.b, self.c)
+    }
+}
+
+/// An executed instruction, with the registers either side of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub pointer: usize,
+    pub instruction: [u8; 2],
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<u8>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [code, operand] = self.instruction;
+        let opcode = Opcode::from_code(code);
+        let instruction = format!("{} {}", opcode.mnemonic(), operand_name(opcode, operand));
+        write!(f, "{:>3}: {:<7}  {}  ->  {}", self.pointer, instruction.trim_end(), self.before, self.after)?;
+        if let Some(value) = self.output {
+            write!(f, "  out {}", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Before running the instruction at this address.
+    Pointer(usize),
+    /// Once this many values have been output.
+    Outputs(usize),
+}
+
+/// Why a debugger run stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    Breakpoint(Breakpoint),
+    /// Still running after the maximum number of steps, so it may never halt.
+    StepLimit,
+    /// The instruction at this address has the reserved combo operand 7.
+    InvalidOperand(usize),
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stop::Halted => write!(f, "halted"),
+            Stop::Breakpoint(Breakpoint::Pointer(pointer)) => write!(f, "breakpoint at {}", pointer),
+            Stop::Breakpoint(Breakpoint::Outputs(count)) => write!(f, "breakpoint after {} outputs", count),
+            Stop::StepLimit => write!(f, "step limit reached, the program may never halt"),
+            Stop::InvalidOperand(pointer) => write!(f, "invalid combo operand 7 at {}", pointer),
+        }
+    }
+}
+
+/// Runs a program an instruction at a time, with the pointer as an address
+/// into the program's numbers, stopping at breakpoints.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    memory: Vec<u8>,
+    pub registers: Registers,
+    pub pointer: usize,
+    pub output: Vec<u8>,
+    /// Instructions run so far.
+    pub steps: usize,
+    breakpoints: Vec<Breakpoint>,
+    /// Stopped at a pointer breakpoint, which is passed over when resuming.
+    paused: bool,
+}
+
+impl Debugger {
+    pub fn new(program: &Program) -> Self {
+        Debugger {
+            memory: program.instructions.iter().flatten().copied().collect(),
+            registers: Registers { a: program.register_a, b: program.register_b, c: program.register_c },
+            pointer: 0,
+            output: Vec::new(),
+            steps: 0,
+            breakpoints: Vec::new(),
+            paused: false,
+        }
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> &mut Self {
+        self.breakpoints.push(breakpoint);
+        self
+    }
+
+    /// Whether the pointer has moved past the program.
+    pub fn halted(&self) -> bool {
+        self.pointer + 1 >= self.memory.len()
+    }
+
+    /// Run the next instruction, ignoring breakpoints.
+    pub fn step(&mut self) -> Result<Step, Stop> {
+        if self.halted() {
+            return Err(Stop::Halted);
+        }
+
+        let instruction = [self.memory[self.pointer], self.memory[self.pointer + 1]];
+        let before = self.registers;
+        let (next, output) = self.registers.execute(Opcode::from_code(instruction[0]), instruction[1], self.pointer)
+            .ok_or(Stop::InvalidOperand(self.pointer))?;
+
+        let step = Step { pointer: self.pointer, instruction, before, after: self.registers, output };
+        self.output.extend(output);
+        self.pointer = next;
+        self.steps += 1;
+
+        Ok(step)
+    }
+
+    /// Run until the program halts, a breakpoint is reached or after `max_steps`
+    /// instructions, passing each step to `on_step`.
+    pub fn run(&mut self, max_steps: usize, mut on_step: impl FnMut(&Step)) -> Stop {
+        let mut resuming = mem::take(&mut self.paused);
+        for _ in 0..max_steps {
+            if !resuming && self.breakpoints.contains(&Breakpoint::Pointer(self.pointer)) {
+                self.paused = true;
+                return Stop::Breakpoint(Breakpoint::Pointer(self.pointer));
+            }
+            resuming = false;
+
+            let step = match self.step() {
+                Ok(step) => step,
+                Err(stop) => return stop,
+            };
+            on_step(&step);
+
+            let outputs = Breakpoint::Outputs(self.output.len());
+            if step.output.is_some() && self.breakpoints.contains(&outputs) {
+                return Stop::Breakpoint(outputs);
+            }
+        }
+
+        if self.halted() { Stop::Halted } else { Stop::StepLimit }
+    }
+}
+
+/// Source of a value read by a decoded instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Literal(u64),
+    A,
+    B,
+    C,
+}
+
+/// Instruction decoded once, with its operand ready to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Adv(Source),
+    Bxl(u64),
+    Bst(Source),
+    Jnz(usize),
+    Bxc,
+    Out(Source),
+    Bdv(Source),
+    Cdv(Source),
+    /// Reserved combo operand 7, which stops the program if it is run.
+    Invalid,
+}
+
+/// Program decoded at every address, jumps to odd addresses included, for
+/// running many times over.
+#[derive(Debug, Clone)]
+pub struct Compiled {
+    ops: Vec<Op>,
+}
+
+impl Compiled {
+    pub fn new(instructions: &[[u8; 2]]) -> Self {
+        let memory = instructions.iter().flatten().copied().collect::<Vec<_>>();
+        let ops = memory.windows(2)
+            .map(|pair| {
+                let operand = pair[1];
+                let source = match operand {
+                    0..4 => Some(Source::Literal(operand as u64)),
+                    4 => Some(Source::A),
+                    5 => Some(Source::B),
+                    6 => Some(Source::C),
+                    _ => None,
+                };
+                let combo = |op: fn(Source) -> Op| source.map_or(Op::Invalid, op);
+
+                match Opcode::from_code(pair[0]) {
+                    Opcode::Adv => combo(Op::Adv),
+                    Opcode::Bxl => Op::Bxl(operand as u64),
+                    Opcode::Bst => combo(Op::Bst),
+                    Opcode::Jnz => Op::Jnz(operand as usize),
+                    Opcode::Bxc => Op::Bxc,
+                    Opcode::Out => combo(Op::Out),
+                    Opcode::Bdv => combo(Op::Bdv),
+                    Opcode::Cdv => combo(Op::Cdv),
+                }
+            })
+            .collect();
+
+        Compiled { ops }
+    }
+
+    /// Run until the program stops, after at most `max_steps` instructions,
+    /// passing each output to `out` and stopping early if it returns false.
+    #[inline]
+    fn execute(&self,
+               registers: &mut Registers,
+               max_steps: usize,
+               mut out: impl FnMut(u8) -> bool) -> Option<Stop> {
+        let Registers { mut a, mut b, mut c } = *registers;
+        let mut pointer = 0;
+        let mut steps = 0;
+
+        let stop = loop {
+            let Some(&op) = self.ops.get(pointer) else {
+                break Some(Stop::Halted);
+            };
+            if steps == max_steps {
+                break Some(Stop::StepLimit);
+            }
+            steps += 1;
+
+            let value = |source| match source {
+                Source::Literal(value) => value,
+                Source::A => a,
+                Source::B => b,
+                Source::C => c,
+            };
+            pointer += 2;
+            match op {
+                Op::Adv(source) => a = shift_right(a, value(source)),
+                Op::Bxl(literal) => b ^= literal,
+                Op::Bst(source) => b = value(source) & 7,
+                Op::Jnz(target) => if a != 0 {
+                    pointer = target;
+                },
+                Op::Bxc => b ^= c,
+                Op::Out(source) => if !out((value(source) & 7) as u8) {
+                    break None;
+                },
+                Op::Bdv(source) => b = shift_right(a, value(source)),
+                Op::Cdv(source) => c = shift_right(a, value(source)),
+                Op::Invalid => break Some(Stop::InvalidOperand(pointer - 2)),
+            }
+        };
+
+        *registers = Registers { a, b, c };
+        stop
+    }
+
+    /// Run from the registers, adding to the output, until the program stops.
+    pub fn run(&self, registers: &mut Registers, output: &mut Vec<u8>, max_steps: usize) -> Stop {
+        self.execute(registers, max_steps, |value| {
+            output.push(value);
+            true
+        }).unwrap()
+    }
+
+    /// Whether the program halts having output exactly `expected`, giving up
+    /// as soon as an output differs.
+    pub fn outputs(&self, mut registers: Registers, expected: &[u8], max_steps: usize) -> bool {
+        let mut count = 0;
+        let stop = self.execute(&mut registers, max_steps, |value| {
+            count += 1;
+            expected.get(count - 1) == Some(&value)
+        });
+
+        stop == Some(Stop::Halted) && count == expected.len()
+    }
+}
+
+/// Whether the program outputs exactly `expected` when run with a different A.
+pub fn outputs_with_a(program: &Program, compiled: &Compiled, register_a: u64, expected: &[u8]) -> bool {
+    let registers = Registers { a: register_a, b: program.register_b, c: program.register_c };
+    compiled.outputs(registers, expected, QUINE_MAX_STEPS)
+}
+
+/// Whether the program is a loop that the digits of A can be found for one at a
+/// time, from the last output back: a body with no jumps that shifts A right by
+/// 3 once, outputs once and sets B and C before reading them, then a `jnz 0`.
+pub fn is_simple_loop(instructions: &[[u8; 2]]) -> bool {
+    let Some((&last, body)) = instructions.split_last() else {
+        return false;
+    };
+    let count = |opcode: Opcode| body.iter().filter(|&&[code, _]| code == opcode.code()).count();
+    if last != [Opcode::Jnz.code(), 0] || count(Opcode::Jnz) > 0 || count(Opcode::Out) != 1 ||
+        !body.contains(&[Opcode::Adv.code(), 3]) || count(Opcode::Adv) != 1 {
+        return false;
+    }
+
+    // Each iteration only depends on A when nothing is carried over in B or C
+    let (mut b_set, mut c_set) = (false, false);
+    for &[code, operand] in body {
+        let opcode = Opcode::from_code(code);
+        let reads_combo = |register| opcode.takes_combo() && operand == register;
+        let reads_b = reads_combo(5) || matches!(opcode, Opcode::Bxl | Opcode::Bxc);
+        let reads_c = reads_combo(6) || opcode == Opcode::Bxc;
+        if (reads_b && !b_set) || (reads_c && !c_set) {
+            return false;
+        }
+
+        b_set |= matches!(opcode, Opcode::Bst | Opcode::Bdv);
+        c_set |= opcode == Opcode::Cdv;
+    }
+
+    true
+}
+
+/// Smallest A for a simple loop, choosing its octal digits from the top down so
+/// that each iteration outputs the matching value.
+fn simple_loop_quine(program: &Program, expected: &[u8]) -> Option<u64> {
+    let body = Compiled::new(&program.instructions[..program.instructions.len() - 1]);
+
+    // Depth first with the digits in increasing order, so the first A found is the smallest
+    fn search(program: &Program, body: &Compiled, expected: &[u8], high: u64) -> Option<u64> {
+        let Some((&digit, rest)) = expected.split_last() else {
+            return Some(high);
+        };
+        (0..8)
+            .filter_map(|low| high.checked_mul(8).map(|a| a + low))
+            .filter(|&a| a != 0 && outputs_with_a(program, body, a, &[digit]))
+            .find_map(|a| search(program, body, rest, a))
+    }
+
+    search(program, &body, expected, 0)
+}
+
+
+/// Bit of a register, as the XOR of some bits of the initial A, flipped if `flip`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Bit {
+    mask: u64,
+    flip: bool,
+}
+
+impl Bit {
+    fn variable(index: usize) -> Self {
+        Bit { mask: 1 << index, flip: false }
+    }
+
+    fn xor(self, other: Bit) -> Self {
+        Bit { mask: self.mask ^ other.mask, flip: self.flip ^ other.flip }
+    }
+
+    /// Value of the bit for a given initial A.
+    fn value(&self, a: u64) -> bool {
+        ((self.mask & a).count_ones() % 2 == 1) ^ self.flip
+    }
+}
+
+type Word = [Bit; 64];
+
+fn constant_word(value: u64) -> Word {
+    std::array::from_fn(|i| Bit { mask: 0, flip: (value >> i) & 1 == 1 })
+}
+
+/// Linear equations over the bits of the initial A, each saying that the bits
+/// in `mask` XOR to `flip`, kept so that each pivot bit appears in one row only.
+#[derive(Debug, Clone, Default)]
+struct Equations {
+    rows: Vec<(u64, Bit)>,
+}
+
+impl Equations {
+    /// Add `bit == value`, leaving the equations unchanged and returning false
+    /// if it contradicts them.
+    fn add(&mut self, bit: Bit, value: bool) -> bool {
+        let mut row = Bit { mask: bit.mask, flip: bit.flip ^ value };
+        for &(pivot, other) in self.rows.iter() {
+            if row.mask & pivot != 0 {
+                row = row.xor(other);
+            }
+        }
+        if row.mask == 0 {
+            return !row.flip;
+        }
+
+        let pivot = row.mask & row.mask.wrapping_neg();
+        for (_, other) in self.rows.iter_mut() {
+            if other.mask & pivot != 0 {
+                *other = other.xor(row);
+            }
+        }
+        self.rows.push((pivot, row));
+
+        true
+    }
+
+    /// Smallest A that satisfies the equations, clearing bits from the top down.
+    fn minimum(&self) -> u64 {
+        let mut equations = self.clone();
+        let mut value = 0;
+        for i in (0..64).rev() {
+            if !equations.add(Bit::variable(i), false) {
+                equations.add(Bit::variable(i), true);
+                value |= 1 << i;
+            }
+        }
+
+        value
+    }
+
+    /// Smallest A that satisfies the equations and leaves none of the `words`
+    /// zero, or `None` if there is none. When the smallest A zeroes a word, the
+    /// answer has one of that word's bits set, so each is tried in turn.
+    /// Gives `Some(None)` for no solution, and `None` once `budget` runs out.
+    fn minimum_nonzero(&self, words: &[Word], budget: &mut usize) -> Option<Option<u64>> {
+        *budget = budget.checked_sub(1)?;
+
+        let value = self.minimum();
+        let Some(zero) = words.iter().find(|word| word.iter().all(|bit| !bit.value(value))) else {
+            return Some(Some(value));
+        };
+
+        let mut bits = zero.iter().filter(|bit| bit.mask != 0).copied().collect::<Vec<_>>();
+        bits.sort_by_key(|bit| (bit.mask, bit.flip));
+        bits.dedup();
+
+        let mut best = None;
+        for bit in bits {
+            let mut equations = self.clone();
+            if equations.add(bit, true) {
+                if let Some(value) = equations.minimum_nonzero(words, budget)? {
+                    best = Some(best.map_or(value, |best: u64| best.min(value)));
+                }
+            }
+        }
+
+        Some(best)
+    }
+}
+
+/// Program state with the registers written in terms of the bits of the initial A.
+#[derive(Debug, Clone)]
+struct Symbolic {
+    registers: [Word; 3],
+    pointer: usize,
+    outputs: usize,
+    equations: Equations,
+    /// Values of A that must not be zero: at the start, and at each jump taken.
+    nonzero: Vec<Word>,
+}
+
+impl Symbolic {
+    fn combo(&self, operand: u8) -> Option<Word> {
+        match operand {
+            0..4 => Some(constant_word(operand as u64)),
+            4..7 => Some(self.registers[operand as usize - 4]),
+            _ => None,
+        }
+    }
+
+    /// Every value the word can take, each with the state constrained to it.
+    fn values(&self, word: &Word) -> Option<Vec<(u64, Symbolic)>> {
+        let unknown = (0..64).filter(|&i| word[i].mask != 0).collect::<Vec<_>>();
+        if unknown.len() > MAX_BRANCH_BITS {
+            return None;
+        }
+
+        let known = (0..64).filter(|&i| word[i].mask == 0 && word[i].flip)
+            .fold(0, |value, i| value | 1 << i);
+        Some((0..1_u64 << unknown.len())
+            .filter_map(|choice| {
+                let mut state = self.clone();
+                let mut value = known;
+                for (j, &i) in unknown.iter().enumerate() {
+                    let set = (choice >> j) & 1 == 1;
+                    if !state.equations.add(word[i], set) {
+                        return None;
+                    }
+                    value |= (set as u64) << i;
+                }
+                Some((value, state))
+            })
+            .collect())
+    }
+
+    /// Follow every way the program can run to output exactly `expected`,
+    /// collecting the smallest A for each, or `None` if it branches too much.
+    fn explore(mut self, memory: &[u8], expected: &[u8], budget: &mut usize, found: &mut Vec<u64>) -> Option<()> {
+        loop {
+            if self.pointer + 1 >= memory.len() {
+                if self.outputs == expected.len() {
+                    found.extend(self.equations.minimum_nonzero(&self.nonzero, budget)?);
+                }
+                return Some(());
+            }
+            *budget = budget.checked_sub(1)?;
+
+            let opcode = Opcode::from_code(memory[self.pointer]);
+            let operand = memory[self.pointer + 1];
+            self.pointer += 2;
+            match opcode {
+                Opcode::Adv | Opcode::Bdv | Opcode::Cdv => {
+                    let target = match opcode {
+                        Opcode::Adv => 0,
+                        Opcode::Bdv => 1,
+                        _ => 2,
+                    };
+                    let Some(amount) = self.combo(operand) else {
+                        return Some(());
+                    };
+                    for (amount, mut state) in self.values(&amount)? {
+                        let a = state.registers[0];
+                        state.registers[target] = std::array::from_fn(|i| usize::try_from(amount).ok()
+                            .and_then(|amount| a.get(i.checked_add(amount)?).copied())
+                            .unwrap_or_default());
+                        state.explore(memory, expected, budget, found)?;
+                    }
+                    return Some(());
+                }
+                Opcode::Bxl => {
+                    let literal = constant_word(operand as u64);
+                    self.registers[1] = std::array::from_fn(|i| self.registers[1][i].xor(literal[i]));
+                }
+                Opcode::Bst => {
+                    let Some(value) = self.combo(operand) else {
+                        return Some(());
+                    };
+                    self.registers[1] = std::array::from_fn(|i| if i < 3 { value[i] } else { Bit::default() });
+                }
+                Opcode::Jnz => {
+                    // Either A is zero and the program carries on, or it jumps
+                    // with A not zero, which is kept until the smallest A is chosen
+                    let mut zero = self.clone();
+                    if self.registers[0].iter().all(|bit| zero.equations.add(*bit, false)) {
+                        zero.explore(memory, expected, budget, found)?;
+                    }
+                    if self.registers[0].iter().all(|bit| bit.mask == 0 && !bit.flip) {
+                        return Some(());
+                    }
+                    if !self.registers[0].iter().any(|bit| bit.mask == 0 && bit.flip) {
+                        self.nonzero.push(self.registers[0]);
+                    }
+                    self.pointer = operand as usize;
+                }
+                Opcode::Bxc => {
+                    self.registers[1] = std::array::from_fn(|i| self.registers[1][i].xor(self.registers[2][i]));
+                }
+                Opcode::Out => {
+                    let (Some(value), Some(&digit)) = (self.combo(operand), expected.get(self.outputs)) else {
+                        return Some(());
+                    };
+                    if !(0..3).all(|i| self.equations.add(value[i], (digit >> i) & 1 == 1)) {
+                        return Some(());
+                    }
+                    self.outputs += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Smallest A found by treating every register bit as a linear combination of
+/// the bits of A, branching on shift amounts and jumps, and keeping the paths
+/// whose outputs can match. Each candidate is still confirmed by running the
+/// program.
+fn constraint_quine(program: &Program, expected: &[u8]) -> Option<u64> {
+    let memory = program.instructions.iter().flatten().copied().collect::<Vec<_>>();
+    let a = std::array::from_fn(Bit::variable);
+    let start = Symbolic {
+        registers: [a, constant_word(program.register_b), constant_word(program.register_c)],
+        pointer: 0,
+        outputs: 0,
+        equations: Equations::default(),
+        nonzero: vec![a],
+    };
+
+    let mut found = Vec::new();
+    let mut budget = CONSTRAINT_BUDGET;
+    start.explore(&memory, expected, &mut budget, &mut found)?;
+    found.sort();
+    found.dedup();
+    let compiled = Compiled::new(&program.instructions);
+    found.into_iter()
+        .find(|&a| outputs_with_a(program, &compiled, a, expected))
+}
+
+/// Lowest positive A that makes the program output its own numbers.
+pub fn find_quine(program: &Program) -> Option<u64> {
+    let expected = program.instructions.iter().flatten().copied().collect::<Vec<_>>();
+
+    // Only trust the digit by digit search for programs of the shape it assumes
+    if is_simple_loop(&program.instructions) {
+        let compiled = Compiled::new(&program.instructions);
+        if let Some(a) = simple_loop_quine(program, &expected)
+            .filter(|&a| outputs_with_a(program, &compiled, a, &expected)) {
+            return Some(a);
+        }
+    }
+
+    constraint_quine(program, &expected)
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(Self::DAY, input);
+        let mut next_line = |expected: &str| lines.next()
+            .ok_or_else(|| ParseError::end_of_input(Self::DAY, input, format!("expected {}", expected)));
+
+        let mut registers = [0_u64; 3];
+        for (register, name) in registers.iter_mut().zip(["A", "B", "C"]) {
+            let line = next_line(&format!("register {}", name))?;
+            *register = line.parse(line.strip_prefix(line.text, &format!("Register {}: ", name))?)?;
+        }
+        let [register_a, register_b, register_c] = registers;
+
+        let blank = next_line("a blank line")?;
+        if !blank.text.is_empty() {
+            return Err(blank.error_at(blank.text, "expected a blank line"));
+        }
+
+        // Program is a list of 3-bit numbers, in opcode/operand pairs
+        let line = next_line("the program")?;
+        let values = line.strip_prefix(line.text, "Program: ")?
+            .split(',')
+            .map(|s| match line.parse::<u8>(s)? {
+                v if v < 8 => Ok(v),
+                _ => Err(line.error_at(s, "expected a 3-bit number")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.len() % 2 != 0 {
+            return Err(line.error_at(line.text, "expected an operand for every opcode"));
+        }
+
+        let instructions = values.chunks(2)
+            .map(|c| [c[0], c[1]])
+            .collect::<Vec<_>>();
+
+        Ok(Program { register_a, register_b, register_c, instructions })
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        // Output up to an invalid operand is not the program's answer
+        let Ok((_, _, _, output)) = run_program(program.register_a,
+                                                program.register_b,
+                                                program.register_c,
+                                                &program.instructions) else {
+            return Answer::Unsolved;
+        };
+        output.iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<String>>()
+            .into()
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        find_quine(program).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    #[test]
+    fn part1_example() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part1(&input), Answer::List(["4", "6", "3", "5", "6", "3", "5", "2", "1", "0"].map(String::from).to_vec()));
+    }
+
+    #[test]
+    fn disassembly() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(disassemble(&input.instructions), "0: adv 1\n2: out A\n4: jnz 0\n");
+        assert_eq!(pseudo_code(&input.instructions), "\
+do {
+    A = A >> 1
+    out A % 8
+} while A != 0
+");
+
+        let instructions = [[2, 4], [1, 5], [7, 5], [4, 3], [3, 12], [5, 6], [0, 3], [3, 0]];
+        assert_eq!(disassemble(&instructions).lines().nth(4), Some(" 8: jnz 12"));
+        assert_eq!(pseudo_code(&instructions), "\
+do {
+    B = A % 8
+    B = B ^ 5
+    C = A >> B
+    B = B ^ C
+    if A != 0 goto L12
+    out C % 8
+L12:
+    A = A >> 3
+} while A != 0
+");
+    }
+
+    #[test]
+    fn assembly() {
+        let program = assemble("\
+A = 2024   ; the rest start at zero
+
+loop:
+    adv 3      ; A = A >> 3
+    out A
+    jnz loop
+").unwrap();
+        assert_eq!(program.to_string(), "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n");
+        assert_eq!(Day17::parse(&program.to_string()).unwrap(), program);
+
+        // Disassembly assembles back to the same program
+        let input = Day17::parse(EXAMPLE).unwrap();
+        let source = disassemble(&input.instructions).lines()
+            .map(|line| line.split_once(": ").unwrap().1)
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(assemble(&source).unwrap().instructions, input.instructions);
+    }
+
+    #[test]
+    fn assembly_errors() {
+        let error = |source| assemble(source).unwrap_err();
+        assert_eq!(error("bst 7").message, "combo operand 7 is reserved");
+        assert_eq!(error("nop\nbst 1").message, "unknown instruction `nop`");
+        assert_eq!(error("bxl A").message, "`bxl` takes a literal operand, not a register");
+        assert_eq!(error("bxl 8").message, "expected a 3-bit operand");
+        assert_eq!(error("out 5").message, "expected a literal up to 3, or `A`, `B` or `C`");
+        assert_eq!(error("jnz end").message, "unknown label `end`");
+        assert_eq!(error("D = 3").message, "expected register `A`, `B` or `C`");
+
+        let error = error("x:\nbxc\nbxc\nbxc\nbxc\nbxc\nbxc 5\nend: out 0\njnz end");
+        assert_eq!((error.line, error.column), (9, 5));
+        assert_eq!(error.message, "label `end` at 12 is beyond a 3-bit jump");
+    }
+
+    #[test]
+    fn debugger_trace() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        let mut debugger = Debugger::new(&input);
+        let mut trace = Vec::new();
+        assert_eq!(debugger.run(1000, |step| trace.push(step.clone())), Stop::Halted);
+
+        assert_eq!(trace.len(), 30);
+        assert_eq!(debugger.output, run_program(729, 0, 0, &input.instructions).unwrap().3);
+        assert_eq!(trace[1].to_string(), "  2: out A    A=364 B=0 C=0  ->  A=364 B=0 C=0  out 4");
+        assert_eq!(trace[29].after, Registers { a: 0, b: 0, c: 0 });
+    }
+
+    #[test]
+    fn debugger_breakpoints() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        let mut debugger = Debugger::new(&input);
+        debugger.add_breakpoint(Breakpoint::Pointer(4))
+            .add_breakpoint(Breakpoint::Outputs(3));
+
+        assert_eq!(debugger.run(1000, |_| ()), Stop::Breakpoint(Breakpoint::Pointer(4)));
+        assert_eq!((debugger.steps, debugger.output.clone()), (2, vec![4]));
+        assert_eq!(debugger.run(1000, |_| ()), Stop::Breakpoint(Breakpoint::Pointer(4)));
+        assert_eq!(debugger.run(1000, |_| ()), Stop::Breakpoint(Breakpoint::Outputs(3)));
+        assert_eq!((debugger.pointer, debugger.output.clone()), (4, vec![4, 6, 3]));
+        assert_eq!(debugger.run(1000, |_| ()), Stop::Breakpoint(Breakpoint::Pointer(4)));
+    }
+
+    #[test]
+    fn debugger_stops() {
+        // A never reaches zero
+        let program = assemble("A = 1\nloop: bxl 1\njnz loop").unwrap();
+        assert_eq!(Debugger::new(&program).run(100, |_| ()), Stop::StepLimit);
+
+        let program = Program { register_a: 1, register_b: 0, register_c: 0, instructions: vec![[1, 0], [5, 7]] };
+        assert_eq!(Debugger::new(&program).run(100, |_| ()), Stop::InvalidOperand(2));
+
+        let mut registers = Registers { a: u64::MAX, b: 0, c: 64 };
+        registers.execute(Opcode::Adv, 6, 0);
+        assert_eq!(registers.a, 0);
+    }
+
+    const QUINE_EXAMPLE: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+    #[test]
+    fn part2_example() {
+        let input = Day17::parse(QUINE_EXAMPLE).unwrap();
+        assert!(is_simple_loop(&input.instructions));
+        assert_eq!(Day17::part2(&input), Answer::Integer(117440));
+
+        let expected = [0, 3, 5, 4, 3, 0];
+        assert_eq!(constraint_quine(&input, &expected), Some(117440));
+    }
+
+    #[test]
+    fn quine_searches_agree() {
+        let program = assemble("\
+loop:
+    bst A
+    bxl 1
+    cdv B
+    bxl 5
+    bxc
+    adv 3
+    out B
+    jnz loop").unwrap();
+        let expected = program.instructions.iter().flatten().copied().collect::<Vec<_>>();
+        assert!(is_simple_loop(&program.instructions));
+
+        assert_eq!(simple_loop_quine(&program, &expected), Some(164541160582845));
+        assert_eq!(constraint_quine(&program, &expected), Some(164541160582845));
+    }
+
+    #[test]
+    fn quine_other_shapes() {
+        // B is carried into the next iteration, so the digits cannot be found one at a time
+        let program = assemble("\
+B = 5
+loop:
+    adv 3
+    bxl 5
+    out B
+    bst A
+    jnz loop").unwrap();
+        assert!(!is_simple_loop(&program.instructions));
+        assert_eq!(Day17::part2(&program), Answer::Integer(0o5617000460));
+
+        // Jumps back need A not zero, which the smallest solution of the
+        // output equations alone, zero, does not satisfy
+        let program = assemble("loop:\nadv 3\nout B\njnz loop").unwrap();
+        assert!(!is_simple_loop(&program.instructions));
+        assert_eq!(constraint_quine(&program, &[0, 0, 0]), Some(0o100));
+        assert_eq!(constraint_quine(&program, &[0]), Some(1));
+
+        // Shifting one bit at a time, the outputs overlap and cannot all match
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert!(!is_simple_loop(&input.instructions));
+        assert_eq!(Day17::part2(&input), Answer::Unsolved);
+    }
+
+    #[test]
+    fn compiled_runs() {
+        // Jumps go to addresses in the program's numbers, here back to `adv 1`
+        let mut registers = Registers { a: 5, b: 0, c: 0 };
+        let mut output = Vec::new();
+        let compiled = Compiled::new(&[[5, 4], [0, 1], [3, 2]]);
+        assert_eq!(compiled.run(&mut registers, &mut output, 100), Stop::Halted);
+        assert_eq!((registers.a, output), (0, vec![5]));
+
+        // Jumping to an odd address reads operands as opcodes: `bxl 5`, then `out B`
+        let (_, b, _, output) = run_program(1, 0, 0, &[[3, 1], [5, 5], [5, 4]]).unwrap();
+        assert_eq!((b, output), (5, vec![5]));
+
+        // Shifts past every bit leave nothing, rather than overflowing
+        let (a, b, c, _) = run_program(u64::MAX, 0, 70, &[[0, 6], [6, 6], [1, 1]]).unwrap();
+        assert_eq!((a, b, c), (0, 1, 70));
+
+        assert_eq!(run_program(1, 0, 0, &[[5, 4], [5, 7]]), Err(Stop::InvalidOperand(2)));
+        let input = Day17::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,5,7").unwrap();
+        assert_eq!(Day17::part1(&input), Answer::Unsolved);
+
+        let compiled = Compiled::new(&[[5, 7]]);
+        assert_eq!(compiled.run(&mut registers, &mut Vec::new(), 100), Stop::InvalidOperand(0));
+        let compiled = Compiled::new(&[[3, 0]]);
+        assert_eq!(compiled.run(&mut Registers { a: 1, b: 0, c: 0 }, &mut Vec::new(), 100), Stop::StepLimit);
+    }
+
+    #[test]
+    fn compiled_outputs() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        let compiled = Compiled::new(&input.instructions);
+        let registers = Registers { a: 729, b: 0, c: 0 };
+        assert!(compiled.outputs(registers, &[4, 6, 3, 5, 6, 3, 5, 2, 1, 0], 1000));
+        assert!(!compiled.outputs(registers, &[4, 6, 3, 5, 6, 3, 5, 2, 1], 1000));
+        assert!(!compiled.outputs(registers, &[4, 6, 3, 5, 6, 3, 5, 2, 1, 0, 0], 1000));
+        assert!(!compiled.outputs(registers, &[4, 7], 1000));
+
+        // The same as stepping through with the debugger
+        let mut debugger = Debugger::new(&input);
+        debugger.run(1000, |_| ());
+        assert_eq!(debugger.output, run_program(729, 0, 0, &input.instructions).unwrap().3);
+    }
+
+    #[test]
+    fn instructions() {
+        // If register C contains 9, the program 2,6 would set register B to 1
+        assert_eq!(run_program(0, 0, 9, &[[2, 6]]).unwrap().1, 1);
+        // If register A contains 10, the program 5,0,5,1,5,4 would output 0,1,2
+        assert_eq!(run_program(10, 0, 0, &[[5, 0], [5, 1], [5, 4]]).unwrap().3, vec![0, 1, 2]);
+        // If register B contains 29, the program 1,7 would set register B to 26
+        assert_eq!(run_program(0, 29, 0, &[[1, 7]]).unwrap().1, 26);
+        // If register B contains 2024 and register C contains 43690, the program 4,0 would set register B to 44354
+        assert_eq!(run_program(0, 2024, 43690, &[[4, 0]]).unwrap().1, 44354);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::grid::{Grid, Point};
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+use crate::render::{Cell, Colour, Picture};
+use crate::search::{astar, SearchResult};
+
+pub struct Day18;
+
+/// Number of bytes that have fallen when part 1 is measured.
+pub const FALLEN_BYTES: usize = 1024;
+
+/// Search for the shortest paths avoiding fallen bytes.
+pub fn search(map: &Grid<u8>,
+              start_location: Point,
+              end_location: Point) -> SearchResult<Point, u64> {
+    astar([start_location],
+          |&location| map.neighbours4(location)
+              .filter(|&neighbour| map[neighbour] == 0)
+              .map(|neighbour| (neighbour, 1))
+              .collect::<Vec<_>>(),
+          |location| location.manhattan(end_location) as u64,
+          |&location| location == end_location)
+}
+
+/// Length of the shortest path avoiding fallen bytes, `None` if there is none.
+pub fn explore(map: &Grid<u8>,
+               start_location: Point,
+               end_location: Point) -> Option<u64> {
+    search(map, start_location, end_location).cost()
+}
+
+pub fn fill_map(map: &Grid<u8>,
+                coords: &[Point],
+                num_coords: usize) -> Grid<u8> {
+    let mut map_clone = map.clone();
+    coords.iter().take(num_coords).for_each(|c| map_clone[*c] = 1);
+
+    map_clone
+}
+
+/// Empty memory space just big enough for all the bytes.
+fn empty_map(coords: &[Point]) -> Grid<u8> {
+    let max_row = coords.iter().map(|c| c.row).max().unwrap_or(0);
+    let max_col = coords.iter().map(|c| c.col).max().unwrap_or(0);
+
+    Grid::new(max_row as usize + 1, max_col as usize + 1, 0)
+}
+
+/// Shortest path length from the top-left to the bottom-right corner after `num_coords` bytes.
+pub fn shortest_path(coords: &[Point], num_coords: usize) -> Option<u64> {
+    let map = fill_map(&empty_map(coords), coords, num_coords);
+    let end = Point::new(map.num_rows() as i32 - 1, map.num_cols() as i32 - 1);
+
+    explore(&map, Point::new(0, 0), end)
+}
+
+/// First byte that cuts off the exit, searching from `num_coords` bytes onwards,
+/// `None` if the exit is still reachable once all the bytes have fallen.
+pub fn first_blocking(coords: &[Point], num_coords: usize) -> Option<Point> {
+    let map = empty_map(coords);
+
+    let start = Point::new(0, 0);
+    let end = Point::new(map.num_rows() as i32 - 1, map.num_cols() as i32 - 1);
+    if explore(&fill_map(&map, coords, coords.len()), start, end).is_some() {
+        return None;
+    }
+
+    // Search from the start if the exit is already cut off by `num_coords` bytes
+    let mut left = num_coords.min(coords.len());
+    if explore(&fill_map(&map, coords, left), start, end).is_none() {
+        left = 0;
+    }
+    let mut right = coords.len();
+    while left < right {
+        let mid = (left + right) / 2;
+        let mid_map = fill_map(&map, coords, mid);
+        if explore(&mid_map, start, end).is_none() {
+            right = mid;
+        } else {
+            left = mid + 1;
+        }
+    }
+
+    // The exit is reachable before any bytes fall, so at least one has
+    Some(coords[left - 1])
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Stored as (row, col), from X,Y
+        let coords = lines(Self::DAY, input)
+            .map(|line| {
+                let (x, y) = line.split_once(line.text, ",")?;
+                Ok(Point::new(line.parse::<u16>(y)? as i32, line.parse::<u16>(x)? as i32))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if coords.is_empty() {
+            return Err(ParseError::end_of_input(Self::DAY, input, "expected byte coordinates"));
+        }
+
+        Ok(coords)
+    }
+
+    fn part1(coords: &Self::Input) -> Answer {
+        shortest_path(coords, FALLEN_BYTES).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(coords: &Self::Input) -> Answer {
+        // Coordinates are stored as (row, col), the answer is X,Y
+        first_blocking(coords, FALLEN_BYTES)
+            .map_or(Answer::Unsolved, |byte| format!("{},{}", byte.col, byte.row).into())
+    }
+
+    fn render(coords: &Self::Input) -> Option<Picture> {
+        // Memory space for part 1, with its shortest path if there is one
+        let map = fill_map(&empty_map(coords), coords, FALLEN_BYTES);
+        let end = Point::new(map.num_rows() as i32 - 1, map.num_cols() as i32 - 1);
+
+        let mut picture = Picture::new(&map, |&byte| if byte == 1 {
+            Cell::new('#', Colour::RED)
+        } else {
+            Cell::new('.', Colour::DARK_GREY)
+        });
+        if let Some(path) = search(&map, Point::new(0, 0), end).path() {
+            picture.overlay(path, Cell::new('O', Colour::GREEN));
+        }
+
+        Some(picture)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn shortest_path_example() {
+        let coords = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(shortest_path(&coords, 12), Some(22));
+    }
+
+    #[test]
+    fn first_blocking_example() {
+        // Stored as (row, col), so 6,1 in X,Y
+        let coords = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(first_blocking(&coords, 12), Some(Point::new(1, 6)));
+    }
+
+    #[test]
+    fn walled_off_exit() {
+        // Start boxed in by the first two bytes
+        let coords = Day18::parse("1,0\n0,1\n2,2").unwrap();
+        assert_eq!(Day18::part1(&coords), Answer::Unsolved);
+    }
+
+    #[test]
+    fn short_input() {
+        assert!(Day18::parse("").is_err());
+
+        // Fewer bytes than fall for part 1, none of them in the way
+        let coords = Day18::parse("0,2\n2,0").unwrap();
+        assert_eq!(Day18::part1(&coords), Answer::Integer(4));
+        assert_eq!(Day18::part2(&coords), Answer::Unsolved);
+
+        // Blocked before `num_coords` bytes have fallen
+        let coords = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(first_blocking(&coords, 24), Some(Point::new(1, 6)));
+    }
+}
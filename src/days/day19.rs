@@ -0,0 +1,129 @@
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+use std::collections::HashMap;
+
+pub struct Day19;
+
+pub fn match_design(design: &[char], patterns: &[Vec<char>]) -> bool {
+    let mut stack = Vec::new();
+    stack.push(0_usize);
+
+    while let Some(idx) = stack.pop() {
+        if idx == design.len() {
+            return true;
+        }
+
+        for p in patterns {
+            let len = p.len();
+            if design.len() >= idx + len && design[idx..idx + len] == *p {
+                stack.push(idx + len);
+            }
+        }
+    }
+
+    false
+}
+
+
+pub fn count_designs(design: &[char], patterns: &[Vec<char>]) -> u64 {
+    let design_str = design.iter().collect::<String>();
+    let design_patterns = patterns.iter()
+        .filter(|p| design_str.contains(p.iter().collect::<String>().as_str()))
+        .collect::<Vec<_>>();
+
+    let mut memo: HashMap<usize, u64> = HashMap::new();
+    count_recursion(design, &design_patterns, 0, &mut memo)
+}
+
+pub fn count_recursion(design: &[char],
+                       design_patterns: &[&Vec<char>],
+                       idx: usize,
+                       memo: &mut HashMap<usize, u64>) -> u64 {
+    if idx == design.len() {
+        return 1;
+    }
+
+    if let Some(&cached_result) = memo.get(&idx) {
+        return cached_result;
+    }
+
+    let mut count = 0;
+    for p in design_patterns.iter() {
+        let len = p.len();
+        if design.len() >= idx + len && design[idx..idx + len] == **p {
+            count += count_recursion(design, design_patterns, idx + len, memo);
+        }
+    }
+
+    memo.insert(idx, count);
+
+    count
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = (Vec<Vec<char>>, Vec<Vec<char>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(Self::DAY, input);
+        let line = lines.next()
+            .ok_or_else(|| ParseError::end_of_input(Self::DAY, input, "expected towel patterns"))?;
+        let patterns = line.text
+            .split(", ")
+            .map(|s| match s.is_empty() {
+                true => Err(line.error_at(s, "expected a towel pattern")),
+                false => Ok(s.chars().collect::<Vec<_>>()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(blank) = lines.next().filter(|line| !line.text.is_empty()) {
+            return Err(blank.error_at(blank.text, "expected a blank line"));
+        }
+        let designs = lines
+            .map(|line| line.text.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        Ok((patterns, designs))
+    }
+
+    fn part1((patterns, designs): &Self::Input) -> Answer {
+        designs.iter()
+            .filter(|design| match_design(design, patterns))
+            .count().into()
+    }
+
+    fn part2((patterns, designs): &Self::Input) -> Answer {
+        designs.iter()
+            .fold(0, |acc, design| acc + count_designs(design, patterns)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+    #[test]
+    fn part1_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part1(&input), Answer::Integer(6));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part2(&input), Answer::Integer(16));
+    }
+}
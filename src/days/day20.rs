@@ -0,0 +1,159 @@
+use crate::grid::{Grid, Point};
+use crate::parse::{check_border, check_single, ParseError};
+use crate::render::{Cell, Colour, Picture};
+use crate::{Answer, Solution};
+
+pub struct Day20;
+
+/// Minimum number of picoseconds a cheat has to save to be counted.
+pub const THRESHOLD: i32 = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapContent {
+    Wall,
+    Empty,
+    Start,
+    End,
+}
+
+
+pub fn find_start(map: &Grid<MapContent>) -> Point {
+    map.find(&MapContent::Start).expect("map has a start")
+}
+
+
+/// Single route from the start to the end, `None` if the track does not lead there.
+pub fn initial_path(map: &Grid<MapContent>) -> Option<Vec<Point>> {
+    let mut route = Vec::new();
+    let mut location = find_start(map);
+    let mut previous = location;
+    route.push(location);
+
+    while map[location] != MapContent::End {
+        let new_location = map.neighbours4(location)
+            .find(|&new_location| new_location != previous && map[new_location] != MapContent::Wall)?;
+
+        // Going round a loop rather than along a track
+        if route.len() > map.num_rows() * map.num_cols() {
+            return None;
+        }
+
+        previous = location;
+        location = new_location;
+        route.push(location);
+    }
+
+    Some(route)
+}
+
+
+pub fn find_shortcuts(path: &[Point], threshold: i32, shortcut: i32) -> usize {
+    let mut count: usize = 0;
+    for idx1 in 0..path.len() {
+        for idx2 in idx1 + 1..path.len() {
+            let diff = path[idx1].manhattan(path[idx2]) as i32;
+
+            if diff <= shortcut {
+                let dist = (idx2 - idx1) as i32 - diff;
+                if dist >= threshold {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Grid<MapContent>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(Self::DAY, input, "`#`, `S`, `E` or `.`", |c| match c {
+            '#' => Some(MapContent::Wall),
+            'S' => Some(MapContent::Start),
+            'E' => Some(MapContent::End),
+            '.' => Some(MapContent::Empty),
+            _ => None,
+        })?;
+        check_border(Self::DAY, &map, |val| *val == MapContent::Wall)?;
+        check_single(Self::DAY, input, &map, &MapContent::Start, "start `S`")?;
+        check_single(Self::DAY, input, &map, &MapContent::End, "end `E`")?;
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        initial_path(map).map_or(Answer::Unsolved, |path| find_shortcuts(&path, THRESHOLD, 2).into())
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        initial_path(map).map_or(Answer::Unsolved, |path| find_shortcuts(&path, THRESHOLD, 20).into())
+    }
+
+    fn render(map: &Self::Input) -> Option<Picture> {
+        let mut picture = Picture::new(map, |content| match content {
+            MapContent::Wall => Cell::new('#', Colour::GREY),
+            MapContent::Empty => Cell::new('.', Colour::DARK_GREY),
+            MapContent::Start => Cell::new('S', Colour::YELLOW),
+            MapContent::End => Cell::new('E', Colour::YELLOW),
+        });
+        if let Some(path) = initial_path(map) {
+            picture.path(&path, Colour::CYAN);
+        }
+
+        Some(picture)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn shortcuts_example() {
+        let map = Day20::parse(EXAMPLE).unwrap();
+        let path = initial_path(&map).unwrap();
+        assert_eq!(path.len(), 85);
+        assert_eq!(find_shortcuts(&path, 64, 2), 1);
+        assert_eq!(find_shortcuts(&path, 20, 2), 5);
+    }
+
+    #[test]
+    fn long_shortcuts_example() {
+        let map = Day20::parse(EXAMPLE).unwrap();
+        let path = initial_path(&map).unwrap();
+        assert_eq!(find_shortcuts(&path, 76, 20), 3);
+        assert_eq!(find_shortcuts(&path, 74, 20), 7);
+    }
+
+    #[test]
+    fn no_track_to_the_end() {
+        let map = Day20::parse("#####\n#S#E#\n#####").unwrap();
+        assert_eq!(initial_path(&map), None);
+        assert_eq!(Day20::part1(&map), Answer::Unsolved);
+
+        // Round and round a loop, with the end cut off
+        let map = Day20::parse("#######\n#S..#E#\n#.#.###\n#...###\n#######").unwrap();
+        assert_eq!(Day20::part2(&map), Answer::Unsolved);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::grid::Point;
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+use std::collections::HashMap;
+
+pub struct Day21;
+
+/// Number of directional keypads operated by robots in part 1 and part 2.
+pub const PART1_ROBOTS: usize = 2;
+pub const PART2_ROBOTS: usize = 25;
+
+pub const NUMERIC_KEYPAD: [&str; 4] = ["789", "456", "123", " 0A"];
+pub const DIRECTIONAL_KEYPAD: [&str; 2] = [" ^A", "<v>"];
+
+/// Layout of a keypad, with the gap that a robot arm must never point at.
+#[derive(Debug, Clone)]
+pub struct Keypad {
+    keys: HashMap<char, Point>,
+    gap: Point,
+}
+
+impl Keypad {
+    /// Keypad from its rows, a space marking the gap.
+    pub fn new(rows: &[&str]) -> Self {
+        let mut keys = HashMap::new();
+        let mut gap = None;
+        for (row, text) in rows.iter().enumerate() {
+            for (col, key) in text.chars().enumerate() {
+                let point = Point::new(row as i32, col as i32);
+                if key == ' ' {
+                    gap = Some(point);
+                } else {
+                    keys.insert(key, point);
+                }
+            }
+        }
+
+        Keypad { keys, gap: gap.expect("keypad has a gap") }
+    }
+
+    /// Shortest ways of moving the arm from one key to another and pressing it,
+    /// as presses on the directional keypad controlling it.
+    pub fn moves(&self, from: char, to: char) -> Vec<String> {
+        let start = self.keys[&from];
+        let end = self.keys[&to];
+        let step = end - start;
+
+        let vertical = if step.row < 0 { "^" } else { "v" }.repeat(step.row.unsigned_abs() as usize);
+        let horizontal = if step.col < 0 { "<" } else { ">" }.repeat(step.col.unsigned_abs() as usize);
+
+        // Mixing directions only costs more, so move one way then the other,
+        // skipping an order that turns the corner over the gap
+        let mut moves = Vec::new();
+        if Point::new(start.row, end.col) != self.gap {
+            moves.push(format!("{}{}A", horizontal, vertical));
+        }
+        if Point::new(end.row, start.col) != self.gap {
+            moves.push(format!("{}{}A", vertical, horizontal));
+        }
+        moves.dedup();
+
+        moves
+    }
+}
+
+/// Shortest number of presses through a chain of directional keypads, cached by key pair and depth.
+pub struct Robots {
+    numeric: Keypad,
+    directional: Keypad,
+    depth: usize,
+    cache: HashMap<(char, char, usize), u64>,
+}
+
+impl Robots {
+    /// Chain with `depth` directional keypads operated by robots between the
+    /// person and the numeric keypad.
+    pub fn new(depth: usize) -> Self {
+        Robots {
+            numeric: Keypad::new(&NUMERIC_KEYPAD),
+            directional: Keypad::new(&DIRECTIONAL_KEYPAD),
+            depth,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Presses needed by the person to type a sequence on the directional keypad
+    /// `depth` levels above them, starting with every arm on `A`.
+    fn sequence_presses(&mut self, sequence: &str, depth: usize) -> u64 {
+        if depth == 0 {
+            return sequence.len() as u64;
+        }
+
+        let mut previous = 'A';
+        let mut presses = 0;
+        for key in sequence.chars() {
+            presses += self.key_presses(previous, key, depth);
+            previous = key;
+        }
+
+        presses
+    }
+
+    fn key_presses(&mut self, from: char, to: char, depth: usize) -> u64 {
+        if let Some(&presses) = self.cache.get(&(from, to, depth)) {
+            return presses;
+        }
+
+        let presses = self.directional.moves(from, to).iter()
+            .map(|moves| self.sequence_presses(moves, depth - 1))
+            .min().unwrap();
+        self.cache.insert((from, to, depth), presses);
+
+        presses
+    }
+
+    /// Fewest presses by the person to type a code on the numeric keypad.
+    pub fn code_presses(&mut self, code: &str) -> u64 {
+        let mut previous = 'A';
+        let mut presses = 0;
+        for key in code.chars() {
+            presses += self.numeric.moves(previous, key).iter()
+                .map(|moves| self.sequence_presses(moves, self.depth))
+                .min().unwrap();
+            previous = key;
+        }
+
+        presses
+    }
+}
+
+/// Sum of the complexities of the codes: presses times the numeric part.
+pub fn complexity(codes: &[String], depth: usize) -> u64 {
+    let mut robots = Robots::new(depth);
+    codes.iter()
+        .map(|code| robots.code_presses(code) * code.trim_end_matches('A').parse::<u64>().unwrap_or(0))
+        .sum()
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(Self::DAY, input)
+            .map(|line| {
+                let keys = line.map_chars("a digit or `A`", |c| (c.is_ascii_digit() || c == 'A').then_some(c))?;
+                if keys.last() != Some(&'A') || keys[..keys.len() - 1].contains(&'A') {
+                    return Err(line.error_at(line.text, "expected a code of digits ending in `A`"));
+                }
+
+                Ok(line.text.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(codes: &Self::Input) -> Answer {
+        complexity(codes, PART1_ROBOTS).into()
+    }
+
+    fn part2(codes: &Self::Input) -> Answer {
+        complexity(codes, PART2_ROBOTS).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A";
+
+    #[test]
+    fn presses_example() {
+        let mut robots = Robots::new(PART1_ROBOTS);
+        let presses = ["029A", "980A", "179A", "456A", "379A"]
+            .map(|code| robots.code_presses(code));
+        assert_eq!(presses, [68, 60, 68, 64, 64]);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part1(&input), Answer::Integer(126384));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part2(&input), Answer::Integer(154115708116294));
+    }
+
+    #[test]
+    fn keypad_moves() {
+        let keypad = Keypad::new(&NUMERIC_KEYPAD);
+        assert_eq!(keypad.moves('A', '0'), vec!["<A"]);
+        // Going up first would pass over the gap
+        assert_eq!(keypad.moves('A', '1'), vec!["^<<A"]);
+        assert_eq!(keypad.moves('2', '9'), vec![">^^A", "^^>A"]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Day21::parse("029B").is_err());
+        assert!(Day21::parse("02A9").is_err());
+        assert!(Day21::parse("").unwrap().is_empty());
+    }
+}
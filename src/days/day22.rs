@@ -0,0 +1,113 @@
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day22;
+
+pub fn gen_next(num: u64) -> u64 {
+    let mask = 16777215; // 2^24 - 1
+    let next1 = ((num << 6) ^ num) & mask;
+    let next2 = ((next1 >> 5) ^ next1) & mask;
+
+    ((next2 << 11) ^ next2) & mask
+}
+
+
+/// Best sequence of four price changes and the bananas it buys, `None` without any buyers.
+pub fn best_pattern(secrets: &[u64]) -> Option<([i8; 4], u64)> {
+    let mut patterns = HashMap::new();
+
+    for secret in secrets {
+        let mut num = *secret;
+
+        let mut unique = HashSet::new();
+        let mut diffs = [0_i8; 4];
+        for idx in 0..2000 {
+            let last = (num % 10) as i8;
+            num = gen_next(num);
+
+            diffs.rotate_left(1);
+            let curr = (num % 10) as i8;
+            diffs[3] = curr - last;
+            if idx >= 3 && unique.insert(diffs) {
+                *patterns.entry(diffs).or_insert(0) += curr as u64;
+            }
+        }
+    }
+
+    patterns.into_iter()
+        .max_by_key(|&(_, count)| count)
+}
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(Self::DAY, input)
+            .map(|line| line.parse::<u64>(line.text))
+            .collect()
+    }
+
+    fn part1(secrets: &Self::Input) -> Answer {
+        let mut total = 0;
+        for secret in secrets {
+            let mut num = *secret;
+            for _ in 0..2000 {
+                num = gen_next(num);
+            }
+            total += num;
+        }
+
+        total.into()
+    }
+
+    fn part2(secrets: &Self::Input) -> Answer {
+        best_pattern(secrets).map_or(Answer::Unsolved, |(_, bananas)| bananas.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+1
+10
+100
+2024";
+    const EXAMPLE_2: &str = "\
+1
+2
+3
+2024";
+
+    #[test]
+    fn part1_example() {
+        let input = Day22::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day22::part1(&input), Answer::Integer(37327623));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day22::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day22::part2(&input), Answer::Integer(23));
+    }
+
+    #[test]
+    fn secret_sequence() {
+        let mut secret = 123;
+        let expected = [15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432, 5908254];
+        for next in expected {
+            secret = gen_next(secret);
+            assert_eq!(secret, next);
+        }
+    }
+
+    #[test]
+    fn best_pattern_example() {
+        assert_eq!(best_pattern(&[1, 2, 3, 2024]), Some(([-2, 1, -1, 3], 23)));
+        assert_eq!(best_pattern(&[]), None);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::parse::{lines, Line, ParseError};
+use crate::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day23;
+
+/// Each computer mapped to the computers it is directly connected to.
+pub type Connections = HashMap<[char; 2], HashSet<[char; 2]>>;
+
+pub fn find_pairs(set: &HashSet<[char; 2]>) -> Vec<([char; 2], [char; 2])> {
+    let mut pairs = Vec::new();
+
+    let vec: Vec<_> = set.iter().collect();
+    for i in 0..vec.len() {
+        for j in i + 1..vec.len() {
+            pairs.push((*vec[i], *vec[j]));
+        }
+    }
+
+    pairs
+}
+
+/// Largest set of computers that are all connected to each other, in sorted order.
+pub fn max_clique(connected: &Connections) -> Vec<[char; 2]> {
+    let mut max_clique = Vec::new();
+    bron_kerbosch(connected,
+                  &mut Vec::new(),
+                  connected.keys().copied().collect(),
+                  HashSet::new(),
+                  &mut max_clique);
+
+    max_clique.sort();
+    max_clique
+}
+
+// Bron-Kerbosch with pivoting: grow the clique from the candidates, skipping
+// nodes already covered by an earlier branch (excluded)
+fn bron_kerbosch(connected: &Connections,
+                 clique: &mut Vec<[char; 2]>,
+                 mut candidates: HashSet<[char; 2]>,
+                 mut excluded: HashSet<[char; 2]>,
+                 max_clique: &mut Vec<[char; 2]>) {
+    if candidates.is_empty() && excluded.is_empty() {
+        if clique.len() > max_clique.len() {
+            *max_clique = clique.clone();
+        }
+        return;
+    }
+
+    // Neighbours of the pivot are found in a branch of their own
+    let pivot = *candidates.union(&excluded)
+        .max_by_key(|node| connected[*node].intersection(&candidates).count())
+        .unwrap();
+    let to_visit = candidates.difference(&connected[&pivot])
+        .copied()
+        .collect::<Vec<_>>();
+
+    for node in to_visit {
+        let neighbours = &connected[&node];
+        clique.push(node);
+        bron_kerbosch(connected,
+                      clique,
+                      candidates.intersection(neighbours).copied().collect(),
+                      excluded.intersection(neighbours).copied().collect(),
+                      max_clique);
+        clique.pop();
+
+        candidates.remove(&node);
+        excluded.insert(node);
+    }
+}
+
+fn computer_name(line: &Line, s: &str) -> Result<[char; 2], ParseError> {
+    s.chars().collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_| line.error_at(s, "expected a two letter computer name"))
+}
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Connections;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut connected = HashMap::new();
+        for line in lines(Self::DAY, input) {
+            let (first, second) = line.split_once(line.text, "-")?;
+            let connection = [computer_name(&line, first)?, computer_name(&line, second)?];
+
+            connected.entry(connection[0]).or_insert(HashSet::new()).insert(connection[1]);
+            connected.entry(connection[1]).or_insert(HashSet::new()).insert(connection[0]);
+        }
+
+        Ok(connected)
+    }
+
+    fn part1(connected: &Self::Input) -> Answer {
+        let mut triplets = HashSet::new();
+        for key in connected.keys() {
+            for (first, second) in find_pairs(&connected[key]) {
+                if connected[&first].contains(&second) &&
+                    (key[0] == 't' || first[0] == 't' || second[0] == 't') {
+                    let mut sorted_triplet = [*key, first, second];
+                    sorted_triplet.sort();
+                    triplets.insert((sorted_triplet[0], sorted_triplet[1], sorted_triplet[2]));
+                }
+            }
+        }
+
+        triplets.len().into()
+    }
+
+    fn part2(connected: &Self::Input) -> Answer {
+        // No computers, so no password rather than an empty one
+        let clique = max_clique(connected);
+        if clique.is_empty() {
+            return Answer::Unsolved;
+        }
+
+        clique.iter()
+            .map(|x| format!("{}{}", x[0], x[1]))
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn part1_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part1(&input), Answer::Integer(7));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part2(&input), Answer::List(["co", "de", "ka", "ta"].map(String::from).to_vec()));
+    }
+
+    #[test]
+    fn no_connections() {
+        let input = Day23::parse("").unwrap();
+        assert_eq!(Day23::part1(&input), Answer::Integer(0));
+        assert_eq!(Day23::part2(&input), Answer::Unsolved);
+    }
+}
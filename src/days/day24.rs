@@ -0,0 +1,477 @@
+use crate::netlist::{read_bus, write_bus, GateKind, Netlist, NetlistError};
+use crate::parse::{lines, ParseError};
+use crate::{Answer, Solution};
+use std::collections::{BTreeSet, HashMap};
+
+pub struct Day24;
+
+/// Initial wire values, keyed by wire name.
+pub type Values = HashMap<String, bool>;
+
+/// Gates keyed by `[input, input, output]` wire names, with the gate type as value.
+pub type Operations = HashMap<[String; 3], String>;
+
+/// Two wires whose gate outputs are exchanged.
+pub type WirePair = (String, String);
+
+/// Number of gate output pairs swapped in the puzzle input.
+pub const SWAPPED_PAIRS: usize = 4;
+
+/// Wires beyond the swapped ones that may be flagged by mistake.
+pub const MAX_EXTRA_SUSPICIOUS: usize = 2;
+
+/// Random additions a repaired adder has to get right.
+pub const ADDER_TRIALS: usize = 100;
+
+/// Small xorshift generator, for reproducible random test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // Zero would get stuck at zero
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Netlist of the gates, with the initial values as its inputs.
+pub fn build_netlist(initial_values: &Values, operations: &Operations) -> Result<Netlist, NetlistError> {
+    let mut inputs = initial_values.keys().map(|wire| wire.as_str()).collect::<Vec<_>>();
+    inputs.sort();
+
+    Netlist::new(inputs, operations.iter()
+        .map(|([left, right, output], operation)| {
+            let kind = GateKind::from_name(operation).expect("gate types are checked when parsed");
+            (kind, [left.as_str(), right.as_str(), output.as_str()])
+        }))
+}
+
+/// Number on the `z` bus, `None` if it needs more than 128 bits.
+pub fn part1(initial_values: &Values, operations: &Operations) -> Option<u128> {
+    let netlist = build_netlist(initial_values, operations).expect("netlist is checked when parsed");
+
+    let mut values = netlist.values();
+    for (wire, &value) in initial_values {
+        values[netlist.wire(wire).unwrap()] = value;
+    }
+    netlist.simulate(&mut values);
+
+    // Extract values from target
+    read_bus(&values, &netlist.bus("z"))
+}
+
+/// Values of the wires for the initial values, followed by each addition of
+/// two numbers on the x and y buses.
+pub fn input_vectors(netlist: &Netlist, initial_values: &Values, additions: &[(u128, u128)]) -> Vec<Vec<bool>> {
+    let mut initial = netlist.values();
+    for (wire, &value) in initial_values {
+        initial[netlist.wire(wire).unwrap()] = value;
+    }
+
+    let (x, y) = (netlist.bus("x"), netlist.bus("y"));
+    let mut vectors = vec![initial];
+    for &(x_value, y_value) in additions {
+        let mut values = netlist.values();
+        write_bus(&mut values, &x, x_value);
+        write_bus(&mut values, &y, y_value);
+        vectors.push(values);
+    }
+
+    vectors
+}
+
+/// Bit number of an input or output wire such as `x07`.
+fn bit_of(wire: &str) -> Option<usize> {
+    wire.get(1..)?.parse().ok()
+}
+
+fn is_input(wire: &str) -> bool {
+    wire.starts_with('x') || wire.starts_with('y')
+}
+
+/// Gate outputs that break the structure of a ripple-carry adder:
+///
+/// - every `z` is the XOR of a half sum and a carry, bar the last which is the final carry (an OR);
+/// - an XOR of a half sum and a carry drives a `z`;
+/// - a half sum `x XOR y` feeds an XOR (except for bit 0, which is `z00` itself);
+/// - an AND feeds the OR making a carry (except `x00 AND y00`, which is the first carry).
+pub fn suspicious_wires(initial_values: &Values, operations: &Operations) -> BTreeSet<String> {
+    let bits = initial_values.keys().filter(|wire| wire.starts_with('x')).count();
+    let last_z = format!("z{:02}", bits);
+
+    // Gate types reading each wire
+    let mut readers: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (operator, operation) in operations {
+        readers.entry(&operator[0]).or_default().push(operation);
+        readers.entry(&operator[1]).or_default().push(operation);
+    }
+    let feeds = |wire: &str, operation: &str| readers.get(wire)
+        .is_some_and(|operations| operations.contains(&operation));
+
+    let mut suspicious = BTreeSet::new();
+    for (operator, operation) in operations {
+        let [left, right, output] = operator;
+        let first_bit = bit_of(left) == Some(0) && bit_of(right) == Some(0);
+
+        let wrong = match operation.as_str() {
+            _ if *output == last_z => operation != "OR",
+            _ if output.starts_with('z') && operation != "XOR" => true,
+            "XOR" if is_input(left) && is_input(right) => !first_bit && !feeds(output, "XOR"),
+            "XOR" => !output.starts_with('z'),
+            "AND" => !first_bit && !feeds(output, "OR"),
+            _ => false,
+        };
+        if wrong {
+            suspicious.insert(output.clone());
+        }
+    }
+
+    suspicious
+}
+
+/// Gates with the outputs of each pair of wires exchanged.
+pub fn swap_outputs(operations: &Operations, pairs: &[WirePair]) -> Operations {
+    operations.iter()
+        .map(|([left, right, output], operation)| {
+            let output = pairs.iter()
+                .find_map(|(a, b)| if output == a { Some(b) } else if output == b { Some(a) } else { None })
+                .unwrap_or(output);
+            ([left.clone(), right.clone(), output.clone()], operation.clone())
+        })
+        .collect()
+}
+
+/// Whether the gates add the `x` and `y` inputs into `z`, checked on random inputs.
+pub fn is_adder(initial_values: &Values, operations: &Operations, rng: &mut Rng, trials: usize) -> bool {
+    let Ok(netlist) = build_netlist(initial_values, operations) else {
+        return false;
+    };
+    let (x, y, z) = (netlist.bus("x"), netlist.bus("y"), netlist.bus("z"));
+
+    // Simulate 64 additions at a time, one in each lane, with random bits on
+    // every input wire so that buses of any width are covered
+    (0..trials.div_ceil(64)).all(|_| {
+        let mut values = netlist.values::<u64>();
+        for &wire in x.iter().chain(&y) {
+            values[wire] = rng.next_u64();
+        }
+        netlist.simulate(&mut values);
+
+        // Ripple-carry sum of all the lanes, bit by bit
+        let mut carry = 0;
+        (0..z.len()).all(|bit| {
+            let x_bit = x.get(bit).map_or(0, |&wire| values[wire]);
+            let y_bit = y.get(bit).map_or(0, |&wire| values[wire]);
+            let sum = x_bit ^ y_bit ^ carry;
+            carry = (x_bit & y_bit) | (carry & (x_bit ^ y_bit));
+            values[z[bit]] == sum
+        }) && carry == 0
+    })
+}
+
+/// Pairs of swapped outputs that turn the gates back into an adder, found among
+/// the suspicious wires and confirmed by simulating random additions.
+pub fn find_swaps(initial_values: &Values, operations: &Operations) -> Option<Vec<WirePair>> {
+    let suspicious = suspicious_wires(initial_values, operations).into_iter().collect::<Vec<_>>();
+    let mut rng = Rng::new(24);
+
+    // Far more than the swaps can explain, so not an adder at all
+    if suspicious.len() > 2 * SWAPPED_PAIRS + MAX_EXTRA_SUSPICIOUS {
+        return None;
+    }
+
+    fn choose(wires: &[String],
+              pairs: &mut Vec<WirePair>,
+              found: &mut dyn FnMut(&[WirePair]) -> bool) -> bool {
+        if pairs.len() == SWAPPED_PAIRS {
+            return found(pairs);
+        }
+
+        // Pair up the first wire with any later one, or leave it out
+        let Some((first, rest)) = wires.split_first() else {
+            return false;
+        };
+        for (i, second) in rest.iter().enumerate() {
+            let remaining = rest[..i].iter().chain(&rest[i + 1..]).cloned().collect::<Vec<_>>();
+            pairs.push((first.clone(), second.clone()));
+            if choose(&remaining, pairs, found) {
+                return true;
+            }
+            pairs.pop();
+        }
+
+        choose(rest, pairs, found)
+    }
+
+    let mut pairs = Vec::new();
+    let found = choose(&suspicious, &mut pairs, &mut |pairs| {
+        is_adder(initial_values, &swap_outputs(operations, pairs), &mut rng, ADDER_TRIALS)
+    });
+
+    found.then_some(pairs)
+}
+
+pub fn parse_netlist(file_str: &str) -> Result<(Values, Operations), ParseError> {
+    let mut lines = lines(Day24::DAY, file_str);
+
+    // Initial values up to the first blank line
+    let mut initial_values = HashMap::new();
+    loop {
+        let line = lines.next()
+            .ok_or_else(|| ParseError::end_of_input(Day24::DAY, file_str, "expected a blank line before the gates"))?;
+        if line.text.is_empty() {
+            break;
+        }
+        let (wire, value) = line.split_once(line.text, ": ")?;
+        let value = match value {
+            "0" => false,
+            "1" => true,
+            _ => return Err(line.error_at(value, "expected `0` or `1`")),
+        };
+        initial_values.insert(wire.to_string(), value);
+    }
+
+    // Then the gates, `a OP b -> c`
+    let mut operations = HashMap::new();
+    for line in lines {
+        let (inputs, output) = line.split_once(line.text, " -> ")?;
+        let op = inputs.split(' ').collect::<Vec<&str>>();
+        if op.len() != 3 {
+            return Err(line.error_at(inputs, "expected a gate `a OP b`"));
+        }
+        if !["AND", "OR", "XOR"].contains(&op[1]) {
+            return Err(line.error_at(op[1], "expected `AND`, `OR` or `XOR`"));
+        }
+        operations.insert([op[0].to_string(), op[2].to_string(), output.to_string()], op[1].to_string());
+    }
+
+    // Point a loop or an undriven wire out at the first gate that uses it
+    if let Err(error) = build_netlist(&initial_values, &operations) {
+        let wire = error.wire();
+        let error = crate::parse::lines(Day24::DAY, file_str)
+            .find_map(|line| line.text.split(' ')
+                .find(|&part| part == wire)
+                .map(|part| line.error_at(part, error.to_string())))
+            .unwrap_or_else(|| ParseError::end_of_input(Day24::DAY, file_str, error.to_string()));
+        return Err(error);
+    }
+
+    Ok((initial_values, operations))
+}
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = (Values, Operations);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_netlist(input)
+    }
+
+    fn part1((initial_values, operations): &Self::Input) -> Answer {
+        part1(initial_values, operations).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2((initial_values, operations): &Self::Input) -> Answer {
+        let Some(pairs) = find_swaps(initial_values, operations) else {
+            return Answer::Unsolved;
+        };
+
+        let mut ans = pairs.into_iter()
+            .flat_map(|(a, b)| [a, b])
+            .collect::<Vec<_>>();
+        ans.sort();
+        ans.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02";
+    const EXAMPLE_2: &str = "\
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj";
+
+    #[test]
+    fn part1_example() {
+        let input = Day24::parse(EXAMPLE_1).unwrap();
+        assert_eq!(Day24::part1(&input), Answer::Integer(4));
+    }
+
+    #[test]
+    fn part1_larger_example() {
+        let input = Day24::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day24::part1(&input), Answer::Integer(2024));
+    }
+
+    /// Ripple-carry adder of `bits` bits, all inputs zero.
+    fn adder(bits: usize) -> (Values, Operations) {
+        let mut values = HashMap::new();
+        let mut operations = HashMap::new();
+        let mut gate = |left: String, operation: &str, right: String, output: String| {
+            operations.insert([left, right, output], operation.to_string());
+        };
+
+        let mut carry = String::from("c00");
+        gate("x00".into(), "XOR", "y00".into(), "z00".into());
+        gate("x00".into(), "AND", "y00".into(), carry.clone());
+        for bit in 1..bits {
+            let (x, y) = (format!("x{:02}", bit), format!("y{:02}", bit));
+            let sum = format!("s{:02}", bit);
+            let both = format!("a{:02}", bit);
+            let carried = format!("b{:02}", bit);
+            let next = if bit + 1 == bits { format!("z{:02}", bits) } else { format!("c{:02}", bit) };
+
+            gate(x.clone(), "XOR", y.clone(), sum.clone());
+            gate(x, "AND", y, both.clone());
+            gate(sum.clone(), "XOR", carry.clone(), format!("z{:02}", bit));
+            gate(sum, "AND", carry, carried.clone());
+            gate(both, "OR", carried, next.clone());
+            carry = next;
+        }
+        for bit in 0..bits {
+            values.insert(format!("x{:02}", bit), false);
+            values.insert(format!("y{:02}", bit), false);
+        }
+
+        (values, operations)
+    }
+
+    #[test]
+    fn waveform() {
+        let (initial_values, operations) = adder(4);
+        let netlist = build_netlist(&initial_values, &operations).unwrap();
+        let vectors = input_vectors(&netlist, &initial_values, &[(15, 1)]);
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(read_bus(&vectors[1], &netlist.bus("x")), Some(15));
+
+        let mut out = Vec::new();
+        netlist.write_vcd("day24", &vectors, &mut out).unwrap();
+        let vcd = String::from_utf8(out).unwrap();
+        let period = netlist.depth() + 1;
+        assert!(vcd.ends_with(&format!("#{}\n", 2 * period)));
+
+        // The carry ripples through two gates per bit, setting z04 last
+        assert_eq!(netlist.depth(), 7);
+        let z04 = vcd.lines()
+            .find_map(|line| line.strip_suffix(" z04 $end"))
+            .and_then(|var| var.strip_prefix("$var wire 1 "))
+            .unwrap();
+        assert!(vcd.contains(&format!("#{}\n1{}\n#", period + 7, z04)));
+    }
+
+    #[test]
+    fn netlist_errors() {
+        let error = Day24::parse("x00: 1\n\nx00 AND y00 -> z00").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 9));
+        assert_eq!(error.message, "wire `y00` is never driven");
+
+        let error = Day24::parse("x00: 1\n\nx00 AND z01 -> z00\nx00 OR z00 -> z01").unwrap_err();
+        assert!(error.message.contains("is part of a loop"));
+    }
+
+    #[test]
+    fn part2_not_an_adder() {
+        let input = Day24::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day24::part2(&input), Answer::Unsolved);
+    }
+
+    #[test]
+    fn adder_structure() {
+        let (values, operations) = adder(12);
+        assert!(suspicious_wires(&values, &operations).is_empty());
+        assert!(is_adder(&values, &operations, &mut Rng::new(1), ADDER_TRIALS));
+    }
+
+    #[test]
+    fn wide_buses() {
+        // 70 bits of x plus one, carried all the way into z70
+        let (mut values, operations) = adder(70);
+        values.values_mut().for_each(|value| *value = true);
+        (1..70).for_each(|bit| { values.insert(format!("y{:02}", bit), false); });
+        assert_eq!(part1(&values, &operations), Some(1 << 70));
+        assert_eq!(Day24::part1(&(values.clone(), operations.clone())), Answer::Integer(1 << 70));
+
+        assert!(is_adder(&values, &operations, &mut Rng::new(1), ADDER_TRIALS));
+        let swapped = swap_outputs(&operations, &[("z66".to_string(), "c66".to_string())]);
+        assert!(!is_adder(&values, &swapped, &mut Rng::new(1), ADDER_TRIALS));
+    }
+
+    #[test]
+    fn find_swapped_wires() {
+        let (values, operations) = adder(40);
+        let swaps = [("z05", "b05"), ("s10", "a10"), ("z20", "c20"), ("z30", "a30")]
+            .map(|(a, b)| (a.to_string(), b.to_string()));
+        let swapped = swap_outputs(&operations, &swaps);
+        assert!(!is_adder(&values, &swapped, &mut Rng::new(1), ADDER_TRIALS));
+        assert_eq!(suspicious_wires(&values, &swapped).len(), 8);
+
+        assert_eq!(Day24::part2(&(values, swapped)),
+                   Answer::List(["a10", "a30", "b05", "c20", "s10", "z05", "z20", "z30"]
+                       .map(String::from).to_vec()));
+    }
+}
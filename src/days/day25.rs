@@ -0,0 +1,115 @@
+use crate::grid::Grid;
+use crate::parse::{lines, parse_rows, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = (Vec<Vec<usize>>, Vec<Vec<usize>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Schematics are separated by blank lines
+        let mut lines = lines(Self::DAY, input).peekable();
+        let mut schematics = Vec::new();
+        while lines.peek().is_some() {
+            let schematic_vec = parse_rows(lines.by_ref().take_while(|line| !line.text.is_empty()),
+                                           "`#` or `.`",
+                                           |c| match c {
+                                               '#' => Some(true),
+                                               '.' => Some(false),
+                                               _ => None,
+                                           })?;
+            if schematic_vec.is_empty() {
+                return Err(ParseError::end_of_input(Self::DAY, input, "expected a schematic"));
+            }
+            schematics.push(Grid::from_rows(schematic_vec).expect("rows have equal length"));
+        }
+
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+        for schematic in schematics {
+            let col_count = schematic.cols()
+                .map(|col| col.filter(|&v| *v).count())
+                .collect::<Vec<_>>();
+            if schematic.row(0).iter().all(|&c| c) {
+                locks.push(col_count);
+            } else {
+                keys.push(col_count);
+            }
+        }
+
+        Ok((locks, keys))
+    }
+
+    fn part1((locks, keys): &Self::Input) -> Answer {
+        let mut fits = 0;
+        for lock in locks.iter() {
+            for key in keys.iter() {
+                if lock.iter().zip(key.iter()).all(|(lv, kv)| lv + kv <= 7) {
+                    fits += 1;
+                }
+            }
+        }
+
+        fits.into()
+    }
+
+    // There is no puzzle for part 2 on the last day
+    fn part2(_: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####";
+
+    #[test]
+    fn part1_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(Day25::part1(&input), Answer::Integer(3));
+    }
+}
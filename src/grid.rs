@@ -0,0 +1,389 @@
+use crate::parse::{lines, parse_rows, ParseError};
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub};
+
+/// Position in (or offset across) a grid: `row` counts down, `col` across.
+///
+/// Signed, so that steps and positions just off the grid can be represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const UP: Point = Point::new(-1, 0);
+    pub const DOWN: Point = Point::new(1, 0);
+    pub const LEFT: Point = Point::new(0, -1);
+    pub const RIGHT: Point = Point::new(0, 1);
+
+    /// Offsets to the 4 orthogonal neighbours, clockwise from up.
+    pub const NEIGHBOURS_4: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+
+    /// Offsets to the 8 neighbours including diagonals, clockwise from up.
+    pub const NEIGHBOURS_8: [Point; 8] = [
+        Point::new(-1, 0), Point::new(-1, 1), Point::new(0, 1), Point::new(1, 1),
+        Point::new(1, 0), Point::new(1, -1), Point::new(0, -1), Point::new(-1, -1),
+    ];
+
+    pub const fn new(row: i32, col: i32) -> Self {
+        Point { row, col }
+    }
+
+    pub fn manhattan(&self, other: Point) -> u32 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+
+/// One of the four directions to step in on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_left().turn_left()
+    }
+
+    /// Step to take on the grid, north being up.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::UP,
+            Direction::East => Point::RIGHT,
+            Direction::South => Point::DOWN,
+            Direction::West => Point::LEFT,
+        }
+    }
+
+    /// Direction of a single orthogonal step.
+    pub fn from_offset(offset: Point) -> Option<Self> {
+        Direction::ALL.into_iter().find(|direction| direction.offset() == offset)
+    }
+
+    /// Direction of an arrow: `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Direction of a compass letter: `N`, `E`, `S` or `W`.
+    pub fn from_compass(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(Direction::North),
+            'E' => Some(Direction::East),
+            'S' => Some(Direction::South),
+            'W' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+
+/// Rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    num_rows: usize,
+    num_cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(num_rows: usize, num_cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { num_rows, num_cols, cells: vec![value; num_rows * num_cols] }
+    }
+
+    /// Grid from rows of equal length, `None` if they differ.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let num_rows = rows.len();
+        let num_cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != num_cols) {
+            return None;
+        }
+
+        Some(Grid { num_rows, num_cols, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Parse a whole input as a grid of characters, mapping each character.
+    pub fn parse(day: u8,
+                 input: &str,
+                 expected: &str,
+                 map: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        let rows = parse_rows(lines(day, input), expected, map)?;
+        if rows.is_empty() {
+            return Err(ParseError::end_of_input(day, input, "expected a map"));
+        }
+
+        Ok(Grid::from_rows(rows).expect("rows have equal length"))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row >= 0 && point.col >= 0 &&
+            (point.row as usize) < self.num_rows && (point.col as usize) < self.num_cols
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.row as usize * self.num_cols + point.col as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item=Point> {
+        let num_cols = self.num_cols;
+        (0..self.num_rows * num_cols)
+            .map(move |i| Point::new((i / num_cols) as i32, (i % num_cols) as i32))
+    }
+
+    /// Every point of the grid with its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item=(Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Orthogonal neighbours of a point that are on the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item=Point> + '_ {
+        Point::NEIGHBOURS_4.into_iter()
+            .map(move |offset| point + offset)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Neighbours of a point, including diagonals, that are on the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item=Point> + '_ {
+        Point::NEIGHBOURS_8.into_iter()
+            .map(move |offset| point + offset)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// First point (row by row) holding a value.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Every point holding a value, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item=Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.num_cols..(row + 1) * self.num_cols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        self.cells.chunks(self.num_cols.max(1))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item=&T> {
+        self.cells[col..].iter().step_by(self.num_cols)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.num_cols).map(|col| self.col(col))
+    }
+
+    /// Grid of the same shape with each value mapped.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { num_rows: self.num_rows, num_cols: self.num_cols, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("point {} is outside the {}x{} grid", point, self.num_rows, self.num_cols))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (num_rows, num_cols) = (self.num_rows, self.num_cols);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point {} is outside the {}x{} grid", point, num_rows, num_cols))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse(0, "abc\ndef", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn index_and_bounds() {
+        let grid = example();
+        assert_eq!((grid.num_rows(), grid.num_cols()), (2, 3));
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = example();
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+                   vec![Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Point::new(0, 1)).count(), 5);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).map(|p| grid[p]).collect::<String>(), "bcfda");
+    }
+
+    #[test]
+    fn rows_cols_and_find() {
+        let grid = example();
+        assert_eq!(grid.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>(), ["abc", "def"]);
+        assert_eq!(grid.col(1).collect::<String>(), "be");
+        assert_eq!(grid.cols().count(), 3);
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::from_compass('S'), Some(Direction::South));
+        assert_eq!(Direction::from_arrow('x'), None);
+        assert_eq!(Direction::from_offset(Point::LEFT), Some(Direction::West));
+        assert_eq!(Direction::from_offset(Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+}
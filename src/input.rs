@@ -0,0 +1,92 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Environment variable overriding the root directory of the puzzle inputs.
+pub const DATA_ENV: &str = "AOC24_DATA";
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayNN/dayNN.txt` under the data root.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Input source from an optional command-line argument, with `-` meaning stdin.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => InputSource::Default,
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    /// Path that was tried, or `None` for stdin.
+    pub path: Option<PathBuf>,
+    pub source: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "day {}: cannot read input {}: {}", self.day, path.display(), self.source),
+            None => write!(f, "day {}: cannot read input from stdin: {}", self.day, self.source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+
+/// Root directory of the puzzle inputs, `data` unless overridden by `AOC24_DATA`.
+pub fn data_root() -> PathBuf {
+    env::var_os(DATA_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("data"))
+}
+
+/// Path of a file in a day's data directory.
+pub fn day_file(day: u8, file_name: &str) -> PathBuf {
+    data_root().join(format!("day{:02}", day)).join(file_name)
+}
+
+/// Location of the puzzle input for a day under the given data root.
+pub fn input_path_in(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{:02}", day)).join(format!("day{:02}.txt", day))
+}
+
+/// Default location of the puzzle input for a day.
+pub fn input_path(day: u8) -> PathBuf {
+    input_path_in(&data_root(), day)
+}
+
+pub fn read_file(day: u8, path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path)
+        .map_err(|source| InputError { day, path: Some(path.to_path_buf()), source })
+}
+
+pub fn read_input(day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Default => read_file(day, &input_path(day)),
+        InputSource::Path(path) => read_file(day, path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)
+                .map_err(|source| InputError { day, path: None, source })?;
+            Ok(input)
+        }
+    }
+}
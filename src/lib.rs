@@ -0,0 +1,17 @@
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod grid;
+pub mod input;
+pub mod netlist;
+pub mod parse;
+pub mod render;
+pub mod runner;
+pub mod search;
+pub mod solution;
+
+pub use answer::Answer;
+pub use parse::ParseError;
+pub use runner::run;
+pub use solution::Solution;
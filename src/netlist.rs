@@ -0,0 +1,596 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::io;
+use std::ops::{BitAnd, BitOr, BitXor};
+
+/// Value carried by a wire: a `bool`, or a `u64` to simulate 64 inputs at once.
+pub trait Signal: Copy + Default + BitAnd<Output=Self> + BitOr<Output=Self> + BitXor<Output=Self> {}
+
+impl<T: Copy + Default + BitAnd<Output=T> + BitOr<Output=T> + BitXor<Output=T>> Signal for T {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GateKind {
+    And,
+    Or,
+    Xor,
+}
+
+impl GateKind {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "AND" => Some(GateKind::And),
+            "OR" => Some(GateKind::Or),
+            "XOR" => Some(GateKind::Xor),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GateKind::And => "AND",
+            GateKind::Or => "OR",
+            GateKind::Xor => "XOR",
+        }
+    }
+
+    pub fn apply<T: Signal>(&self, left: T, right: T) -> T {
+        match self {
+            GateKind::And => left & right,
+            GateKind::Or => left | right,
+            GateKind::Xor => left ^ right,
+        }
+    }
+}
+
+/// Gate between wires, which are numbered by the netlist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gate {
+    pub kind: GateKind,
+    pub inputs: [usize; 2],
+    pub output: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetlistError {
+    /// A gate reads a wire that is neither an input nor driven by a gate.
+    Floating(String),
+    /// A wire is driven by more than one gate, or is an input and driven too.
+    MultipleDrivers(String),
+    /// A wire depends on its own value.
+    Cycle(String),
+}
+
+impl NetlistError {
+    /// Wire the error is about.
+    pub fn wire(&self) -> &str {
+        match self {
+            NetlistError::Floating(wire) |
+            NetlistError::MultipleDrivers(wire) |
+            NetlistError::Cycle(wire) => wire,
+        }
+    }
+}
+
+impl fmt::Display for NetlistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetlistError::Floating(wire) => write!(f, "wire `{}` is never driven", wire),
+            NetlistError::MultipleDrivers(wire) => write!(f, "wire `{}` is driven more than once", wire),
+            NetlistError::Cycle(wire) => write!(f, "wire `{}` is part of a loop", wire),
+        }
+    }
+}
+
+impl Error for NetlistError {}
+
+/// Gates connected by named wires, ordered so that each gate comes after
+/// the gates driving its inputs.
+#[derive(Debug, Clone)]
+pub struct Netlist {
+    names: Vec<String>,
+    wires: HashMap<String, usize>,
+    inputs: Vec<usize>,
+    gates: Vec<Gate>,
+}
+
+impl Netlist {
+    /// Netlist from its input wires and `(kind, [left, right, output])` gates.
+    pub fn new<'a>(inputs: impl IntoIterator<Item=&'a str>,
+                   gates: impl IntoIterator<Item=(GateKind, [&'a str; 3])>) -> Result<Self, NetlistError> {
+        let mut netlist = Netlist { names: Vec::new(), wires: HashMap::new(), inputs: Vec::new(), gates: Vec::new() };
+        let mut driven = Vec::new();
+        for input in inputs {
+            let wire = netlist.add_wire(input);
+            netlist.inputs.push(wire);
+            driven.push(wire);
+        }
+
+        let gates = gates.into_iter()
+            .map(|(kind, [left, right, output])| Gate {
+                kind,
+                inputs: [netlist.add_wire(left), netlist.add_wire(right)],
+                output: netlist.add_wire(output),
+            })
+            .collect::<Vec<_>>();
+
+        // Each wire needs exactly one driver
+        let mut has_driver = vec![false; netlist.names.len()];
+        for wire in netlist.inputs.iter().copied().chain(gates.iter().map(|gate| gate.output)) {
+            if has_driver[wire] {
+                return Err(NetlistError::MultipleDrivers(netlist.names[wire].clone()));
+            }
+            has_driver[wire] = true;
+        }
+        for gate in gates.iter() {
+            if let Some(&wire) = gate.inputs.iter().find(|&&wire| !has_driver[wire]) {
+                return Err(NetlistError::Floating(netlist.names[wire].clone()));
+            }
+        }
+
+        // Kahn's algorithm: a gate is ready once both inputs are known
+        let mut readers = vec![Vec::new(); netlist.names.len()];
+        let mut waiting = vec![2; gates.len()];
+        for (i, gate) in gates.iter().enumerate() {
+            for wire in gate.inputs {
+                readers[wire].push(i);
+            }
+        }
+        while let Some(wire) = driven.pop() {
+            for &i in readers[wire].iter() {
+                waiting[i] -= 1;
+                if waiting[i] == 0 {
+                    netlist.gates.push(gates[i]);
+                    driven.push(gates[i].output);
+                }
+            }
+        }
+
+        // Any gate left waiting is on, or behind, a loop: walk back through
+        // waiting gates until one comes round again
+        if let Some(mut i) = waiting.iter().position(|&count| count > 0) {
+            let mut drivers = vec![None; netlist.names.len()];
+            for (i, gate) in gates.iter().enumerate() {
+                drivers[gate.output] = Some(i);
+            }
+
+            let mut seen = vec![false; gates.len()];
+            while !seen[i] {
+                seen[i] = true;
+                i = gates[i].inputs.iter()
+                    .filter_map(|&wire| drivers[wire])
+                    .find(|&j| waiting[j] > 0)
+                    .expect("a waiting gate has a waiting driver");
+            }
+            return Err(NetlistError::Cycle(netlist.names[gates[i].output].clone()));
+        }
+
+        Ok(netlist)
+    }
+
+    fn add_wire(&mut self, name: &str) -> usize {
+        if let Some(&wire) = self.wires.get(name) {
+            return wire;
+        }
+
+        self.names.push(name.to_string());
+        self.wires.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    pub fn num_wires(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn wire(&self, name: &str) -> Option<usize> {
+        self.wires.get(name).copied()
+    }
+
+    pub fn name(&self, wire: usize) -> &str {
+        &self.names[wire]
+    }
+
+    pub fn inputs(&self) -> &[usize] {
+        &self.inputs
+    }
+
+    /// Gates in evaluation order.
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Wires named by a prefix and a bit number (`x00`, `x01`, ...), least significant first.
+    pub fn bus(&self, prefix: &str) -> Vec<usize> {
+        let mut bus = self.names.iter().enumerate()
+            .filter_map(|(wire, name)| {
+                let bit = name.strip_prefix(prefix)?;
+                if !bit.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                Some((bit.parse::<usize>().ok()?, wire))
+            })
+            .collect::<Vec<_>>();
+        bus.sort();
+
+        bus.into_iter().map(|(_, wire)| wire).collect()
+    }
+
+    /// Value of every wire at rest, all starting out as the default.
+    pub fn values<T: Signal>(&self) -> Vec<T> {
+        vec![T::default(); self.names.len()]
+    }
+
+    /// Wires that no gate reads, sorted by name.
+    pub fn outputs(&self) -> Vec<usize> {
+        let mut read = vec![false; self.names.len()];
+        for gate in self.gates.iter() {
+            gate.inputs.iter().for_each(|&wire| read[wire] = true);
+        }
+
+        let mut outputs = self.gates.iter()
+            .map(|gate| gate.output)
+            .filter(|&wire| !read[wire])
+            .collect::<Vec<_>>();
+        outputs.sort_by_key(|&wire| &self.names[wire]);
+        outputs
+    }
+
+    /// Graphviz graph with a node per gate and edges labelled by wire,
+    /// drawing the gates driving any of the `highlight` wires in red.
+    pub fn to_dot(&self, highlight: &[usize]) -> String {
+        let mut dot = String::from("digraph netlist {\n    rankdir=LR;\n    node [shape=box];\n");
+
+        // Where each wire comes from: an input node or a gate node, with
+        // prefixes that keep wire and gate IDs apart whatever the wires are called
+        let mut sources = vec![String::new(); self.names.len()];
+        for &wire in self.inputs.iter() {
+            sources[wire] = dot_string(&format!("wire:{}", self.names[wire]));
+            writeln!(dot, "    {} [label={}, shape=plaintext];", sources[wire], dot_string(&self.names[wire])).unwrap();
+        }
+        let gate_ids = (0..self.gates.len())
+            .map(|i| dot_string(&format!("gate:{}", i)))
+            .collect::<Vec<_>>();
+        for (gate, id) in self.gates.iter().zip(&gate_ids) {
+            sources[gate.output] = id.clone();
+            let style = if highlight.contains(&gate.output) { ", style=filled, fillcolor=\"#ff8080\"" } else { "" };
+            writeln!(dot, "    {} [label=\"{}\"{}];", id, gate.kind.name(), style).unwrap();
+        }
+
+        // Wires between gates, then out to the outputs
+        for (gate, id) in self.gates.iter().zip(&gate_ids) {
+            for wire in gate.inputs {
+                writeln!(dot, "    {} -> {} [label={}];", sources[wire], id, dot_string(&self.names[wire])).unwrap();
+            }
+        }
+        for wire in self.outputs() {
+            let output = dot_string(&format!("out:{}", self.names[wire]));
+            writeln!(dot, "    {} [label={}, shape=plaintext];", output, dot_string(&self.names[wire])).unwrap();
+            writeln!(dot, "    {} -> {};", sources[wire], output).unwrap();
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Structural Verilog module with a gate primitive per gate.
+    pub fn to_verilog(&self, module: &str) -> String {
+        let outputs = self.outputs();
+        let mut inputs = self.inputs.clone();
+        inputs.sort_by_key(|&wire| &self.names[wire]);
+
+        let ports = inputs.iter().map(|&wire| format!("input {}", verilog_name(&self.names[wire])))
+            .chain(outputs.iter().map(|&wire| format!("output {}", verilog_name(&self.names[wire]))))
+            .collect::<Vec<_>>();
+        let mut verilog = format!("module {}(\n    {}\n);\n", verilog_name(module), ports.join(",\n    "));
+
+        // Internal wires, in evaluation order
+        for gate in self.gates.iter().filter(|gate| !outputs.contains(&gate.output)) {
+            writeln!(verilog, "    wire {};", verilog_name(&self.names[gate.output])).unwrap();
+        }
+        for (i, gate) in self.gates.iter().enumerate() {
+            writeln!(verilog, "    {} g{}({}, {}, {});",
+                     gate.kind.name().to_lowercase(),
+                     i,
+                     verilog_name(&self.names[gate.output]),
+                     verilog_name(&self.names[gate.inputs[0]]),
+                     verilog_name(&self.names[gate.inputs[1]])).unwrap();
+        }
+        verilog.push_str("endmodule\n");
+
+        verilog
+    }
+
+    /// Number of gate delays for every wire to settle: the longest path from an input.
+    pub fn depth(&self) -> usize {
+        let mut levels = vec![0; self.names.len()];
+        for gate in self.gates.iter() {
+            levels[gate.output] = 1 + levels[gate.inputs[0]].max(levels[gate.inputs[1]]);
+        }
+
+        levels.into_iter().max().unwrap_or(0)
+    }
+
+    /// Write a Value Change Dump of the circuit settling after each vector of
+    /// input values is applied, every gate taking one time unit to switch.
+    /// Only the input wires of a vector are read, and each vector gets the
+    /// time needed for the deepest gate to settle.
+    pub fn write_vcd(&self, module: &str, vectors: &[Vec<bool>], out: &mut impl io::Write) -> io::Result<()> {
+        // Wires in name order, each with a short identifier code
+        let mut wires = (0..self.names.len()).collect::<Vec<_>>();
+        wires.sort_by_key(|&wire| &self.names[wire]);
+        let mut codes = vec![String::new(); self.names.len()];
+
+        writeln!(out, "$timescale 1ns $end")?;
+        writeln!(out, "$scope module {} $end", module)?;
+        for (i, &wire) in wires.iter().enumerate() {
+            codes[wire] = vcd_code(i);
+            writeln!(out, "$var wire 1 {} {} $end", codes[wire], self.names[wire])?;
+        }
+        writeln!(out, "$upscope $end")?;
+        writeln!(out, "$enddefinitions $end")?;
+
+        let period = self.depth() + 1;
+        let mut values = vec![false; self.names.len()];
+        let mut time = 0;
+        for vector in vectors {
+            let mut changed = self.inputs.iter()
+                .copied()
+                .filter(|&wire| values[wire] != vector[wire])
+                .collect::<Vec<_>>();
+            changed.iter().for_each(|&wire| values[wire] = vector[wire]);
+
+            for step in 0..period {
+                // Every gate switches at once, from the values a time unit earlier
+                if step > 0 {
+                    let next = self.gates.iter()
+                        .map(|gate| gate.kind.apply(values[gate.inputs[0]], values[gate.inputs[1]]))
+                        .collect::<Vec<_>>();
+                    changed.clear();
+                    for (gate, value) in self.gates.iter().zip(next) {
+                        if values[gate.output] != value {
+                            values[gate.output] = value;
+                            changed.push(gate.output);
+                        }
+                    }
+                }
+
+                if time == 0 {
+                    writeln!(out, "#0\n$dumpvars")?;
+                    changed = wires.clone();
+                }
+                if !changed.is_empty() {
+                    if time > 0 {
+                        writeln!(out, "#{}", time)?;
+                    }
+                    changed.sort_by_key(|&wire| &codes[wire]);
+                    for &wire in changed.iter() {
+                        writeln!(out, "{}{}", values[wire] as u8, codes[wire])?;
+                    }
+                }
+                if time == 0 {
+                    writeln!(out, "$end")?;
+                }
+                time += 1;
+            }
+        }
+        writeln!(out, "#{}", time)?;
+
+        Ok(())
+    }
+
+    /// Settle every gate output from the input wires' values.
+    pub fn simulate<T: Signal>(&self, values: &mut [T]) {
+        for gate in self.gates.iter() {
+            values[gate.output] = gate.kind.apply(values[gate.inputs[0]], values[gate.inputs[1]]);
+        }
+    }
+}
+
+/// Name as a Verilog identifier, escaped if it is not a plain one.
+/// Quoted Graphviz string, escaping quotes and backslashes.
+fn dot_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn verilog_name(name: &str) -> String {
+    let plain = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') &&
+        name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if plain {
+        name.to_string()
+    } else {
+        format!("\\{} ", name)
+    }
+}
+
+/// Identifier of the `index`th wire in a VCD file, in printable characters.
+fn vcd_code(mut index: usize) -> String {
+    let mut code = String::new();
+    loop {
+        code.push((b'!' + (index % 94) as u8) as char);
+        index /= 94;
+        if index == 0 {
+            return code;
+        }
+    }
+}
+
+/// Number on a bus, least significant wire first, `None` if it needs more than 128 bits.
+/// Wires beyond that only have to be clear, so a bus can be any width.
+pub fn read_bus(values: &[bool], bus: &[usize]) -> Option<u128> {
+    bus.iter().enumerate()
+        .filter(|&(_, &wire)| values[wire])
+        .try_fold(0, |value, (bit, _)| (bit < 128).then(|| value | 1 << bit))
+}
+
+pub fn write_bus(values: &mut [bool], bus: &[usize], value: u128) {
+    for (bit, &wire) in bus.iter().enumerate() {
+        values[wire] = bit < 128 && (value >> bit) & 1 == 1;
+    }
+}
+
+/// Number on a bus in one lane of 64 simulated at once, as for `read_bus`.
+pub fn read_lane(values: &[u64], bus: &[usize], lane: usize) -> Option<u128> {
+    bus.iter().enumerate()
+        .filter(|&(_, &wire)| (values[wire] >> lane) & 1 == 1)
+        .try_fold(0, |value, (bit, _)| (bit < 128).then(|| value | 1 << bit))
+}
+
+pub fn write_lane(values: &mut [u64], bus: &[usize], lane: usize, value: u128) {
+    for (bit, &wire) in bus.iter().enumerate() {
+        let set = bit < 128 && (value >> bit) & 1 == 1;
+        values[wire] = values[wire] & !(1 << lane) | (set as u64) << lane;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two-bit adder with carry out on `s02`.
+    fn two_bit_adder() -> Result<Netlist, NetlistError> {
+        Netlist::new(["a00", "a01", "b00", "b01"], [
+            (GateKind::Xor, ["a01", "b01", "h01"]),
+            (GateKind::Xor, ["h01", "c00", "s01"]),
+            (GateKind::Xor, ["a00", "b00", "s00"]),
+            (GateKind::And, ["a00", "b00", "c00"]),
+            (GateKind::And, ["a01", "b01", "d01"]),
+            (GateKind::And, ["h01", "c00", "e01"]),
+            (GateKind::Or, ["d01", "e01", "s02"]),
+        ])
+    }
+
+    #[test]
+    fn topological_order() {
+        let netlist = two_bit_adder().unwrap();
+        assert_eq!(netlist.gates().len(), 7);
+
+        // Every gate comes after the gates driving it
+        let mut known = netlist.inputs().to_vec();
+        for gate in netlist.gates() {
+            assert!(gate.inputs.iter().all(|wire| known.contains(wire)));
+            known.push(gate.output);
+        }
+    }
+
+    #[test]
+    fn buses() {
+        let netlist = two_bit_adder().unwrap();
+        let names = |bus: Vec<usize>| bus.into_iter().map(|wire| netlist.name(wire)).collect::<Vec<_>>();
+        assert_eq!(names(netlist.bus("s")), ["s00", "s01", "s02"]);
+        assert_eq!(names(netlist.bus("a")), ["a00", "a01"]);
+        assert!(netlist.bus("z").is_empty());
+    }
+
+    #[test]
+    fn simulate_one_and_many() {
+        let netlist = two_bit_adder().unwrap();
+        let (a, b, s) = (netlist.bus("a"), netlist.bus("b"), netlist.bus("s"));
+
+        let mut values = netlist.values();
+        write_bus(&mut values, &a, 3);
+        write_bus(&mut values, &b, 2);
+        netlist.simulate(&mut values);
+        assert_eq!(read_bus(&values, &s), Some(5));
+
+        // Every sum at once, one per lane
+        let mut values = netlist.values::<u64>();
+        for lane in 0..16 {
+            write_lane(&mut values, &a, lane, lane as u128 / 4);
+            write_lane(&mut values, &b, lane, lane as u128 % 4);
+        }
+        netlist.simulate(&mut values);
+        for lane in 0..16 {
+            assert_eq!(read_lane(&values, &s, lane), Some(lane as u128 / 4 + lane as u128 % 4));
+        }
+    }
+
+    #[test]
+    fn dot_export() {
+        let netlist = two_bit_adder().unwrap();
+        let dot = netlist.to_dot(&[netlist.wire("c00").unwrap()]);
+
+        assert!(dot.starts_with("digraph netlist {\n"));
+        assert_eq!(dot.matches("[label=\"XOR\"").count(), 3);
+        assert_eq!(dot.matches("fillcolor").count(), 1);
+        assert!(dot.contains("\"wire:a00\" -> \"gate:"));
+        assert!(dot.contains(" -> \"out:s02\";"));
+    }
+
+    #[test]
+    fn dot_ids_and_labels() {
+        // An input named like a gate, and an output needing escapes
+        let netlist = Netlist::new(["gate:0", "g0"], [
+            (GateKind::And, ["gate:0", "g0", "z\"0\\"]),
+        ]).unwrap();
+        let dot = netlist.to_dot(&[]);
+
+        assert!(dot.contains("    \"wire:gate:0\" -> \"gate:0\" [label=\"gate:0\"];"));
+        assert!(dot.contains("    \"wire:g0\" -> \"gate:0\" [label=\"g0\"];"));
+        assert!(dot.contains("    \"out:z\\\"0\\\\\" [label=\"z\\\"0\\\\\", shape=plaintext];"));
+        assert!(dot.contains("    \"gate:0\" -> \"out:z\\\"0\\\\\";"));
+    }
+
+    #[test]
+    fn verilog_export() {
+        let netlist = Netlist::new(["x00", "y00"], [
+            (GateKind::Xor, ["x00", "y00", "z00"]),
+            (GateKind::And, ["x00", "y00", "1c"]),
+            (GateKind::Or, ["1c", "z00", "z01"]),
+        ]).unwrap();
+
+        let verilog = netlist.to_verilog("adder");
+        assert!(verilog.starts_with("module adder(\n    input x00,\n    input y00,\n    output z01\n);\n"));
+        assert!(verilog.contains("    wire \\1c ;\n"));
+        assert!(verilog.contains("    wire z00;\n"));
+        assert!(verilog.contains(" g2(z01, \\1c , z00);\n"));
+        assert!(verilog.ends_with("endmodule\n"));
+    }
+
+    #[test]
+    fn vcd_ripple() {
+        let netlist = two_bit_adder().unwrap();
+        assert_eq!(netlist.depth(), 3);
+
+        let (a, b) = (netlist.bus("a"), netlist.bus("b"));
+        let vectors = [(1, 1), (3, 1)].map(|(x, y)| {
+            let mut values = netlist.values();
+            write_bus(&mut values, &a, x);
+            write_bus(&mut values, &b, y);
+            values
+        });
+
+        let mut out = Vec::new();
+        netlist.write_vcd("adder", &vectors, &mut out).unwrap();
+        let vcd = String::from_utf8(out).unwrap();
+
+        // Wires are coded in name order: a00 `!`, a01 `"`, ..., s01 `*`, s02 `+`
+        assert!(vcd.contains("$var wire 1 + s02 $end\n"));
+        assert!(vcd.contains("$enddefinitions $end\n#0\n$dumpvars\n1!\n0\"\n1#\n"));
+        // The carry reaches s01 one gate after c00 switches
+        assert!(vcd.contains("#1\n1%\n#2\n1*\n#4\n1\"\n#5\n1(\n#6\n1'\n0*\n#7\n1+\n#8\n"));
+    }
+
+    #[test]
+    fn vcd_codes() {
+        assert_eq!(vcd_code(0), "!");
+        assert_eq!(vcd_code(93), "~");
+        assert_eq!(vcd_code(94), "!\"");
+    }
+
+    #[test]
+    fn errors() {
+        let floating = Netlist::new(["a"], [(GateKind::And, ["a", "b", "c"])]);
+        assert_eq!(floating.unwrap_err(), NetlistError::Floating("b".into()));
+
+        let twice = Netlist::new(["a", "b"], [(GateKind::And, ["a", "b", "c"]), (GateKind::Or, ["a", "b", "c"])]);
+        assert_eq!(twice.unwrap_err(), NetlistError::MultipleDrivers("c".into()));
+
+        let cycle = Netlist::new(["a"], [
+            (GateKind::And, ["a", "d", "b"]),
+            (GateKind::And, ["a", "d", "c"]),
+            (GateKind::Or, ["a", "c", "d"]),
+        ]);
+        // `b` only hangs off the loop
+        assert_eq!(cycle.unwrap_err(), NetlistError::Cycle("d".into()));
+    }
+}
@@ -0,0 +1,224 @@
+use crate::grid::Grid;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error in a puzzle input, pointing at where it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column (in characters), starting at 1.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error for input that stops before something required.
+    pub fn end_of_input(day: u8, input: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            day,
+            line: input.lines().count() + 1,
+            column: 1,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}, line {}, column {}: {}", self.day, self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " (found `{}`)", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+
+/// A line of puzzle input, remembering where it came from for error messages.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// Line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error at a 1-based character column of this line.
+    pub fn error(&self, column: usize, text: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error for a part of this line, which must be a slice of `self.text`.
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> ParseError {
+        self.error(self.column_of(part), part, message)
+    }
+
+    /// Column at which a slice of this line starts.
+    pub fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).saturating_sub(start).min(self.text.len());
+
+        match self.text.get(..offset) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        }
+    }
+
+    /// Parse a slice of this line.
+    pub fn parse<T: FromStr>(&self, part: &str) -> Result<T, ParseError> {
+        part.parse::<T>()
+            .map_err(|_| self.error_at(part, format!("invalid {}", type_name::<T>())))
+    }
+
+    /// Parse a whitespace separated list of values.
+    pub fn parse_fields<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.text.split_ascii_whitespace()
+            .map(|field| self.parse(field))
+            .collect()
+    }
+
+    pub fn split_once(&self, part: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error_at(part, format!("expected `{}`", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        part.strip_prefix(prefix)
+            .ok_or_else(|| self.error_at(part, format!("expected `{}`", prefix)))
+    }
+
+    /// Map each character, reporting the column of any that are rejected.
+    pub fn map_chars<T>(&self,
+                        expected: &str,
+                        map: impl Fn(char) -> Option<T>) -> Result<Vec<T>, ParseError> {
+        self.text.char_indices()
+            .map(|(i, c)| map(c).ok_or_else(|| self.error_at(
+                &self.text[i..i + c.len_utf8()],
+                format!("unexpected character, expected {}", expected))))
+            .collect()
+    }
+}
+
+
+/// Numbered lines of a day's input.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item=Line<'_>> {
+    input.lines().enumerate()
+        .map(move |(i, text)| Line { day, number: i + 1, text })
+}
+
+/// Parse a rectangular block of characters into rows, mapping each character.
+pub fn parse_rows<'a, T>(lines: impl IntoIterator<Item=Line<'a>>,
+                         expected: &str,
+                         map: impl Fn(char) -> Option<T>) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for line in lines {
+        let row = line.map_chars(expected, &map)?;
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(line.error_at(line.text, format!("expected a row of {} characters", first.len())));
+            }
+        } else if row.is_empty() {
+            return Err(line.error(1, "", "expected a row of characters"));
+        }
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+/// Check that the border of a map (starting on the first line of the input) is all wall,
+/// so that walking the map can never step off it.
+pub fn check_border<T>(day: u8,
+                       map: &Grid<T>,
+                       is_wall: impl Fn(&T) -> bool) -> Result<(), ParseError> {
+    let last_row = map.num_rows() as i32 - 1;
+    let last_col = map.num_cols() as i32 - 1;
+
+    match map.iter()
+        .find(|(point, val)| (point.row == 0 || point.col == 0 || point.row == last_row || point.col == last_col) && !is_wall(val)) {
+        Some((point, _)) => Err(ParseError {
+            day,
+            line: point.row as usize + 1,
+            column: point.col as usize + 1,
+            text: String::new(),
+            message: "map must be surrounded by walls".to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Check that a map (starting on the first line of the input) contains exactly one of a tile.
+pub fn check_single<T: PartialEq>(day: u8,
+                                  input: &str,
+                                  map: &Grid<T>,
+                                  tile: &T,
+                                  name: &str) -> Result<(), ParseError> {
+    let mut found = map.find_all(tile);
+
+    match (found.next(), found.next()) {
+        (Some(_), None) => Ok(()),
+        (None, _) => Err(ParseError::end_of_input(day, input, format!("map has no {}", name))),
+        (Some(_), Some(point)) => Err(ParseError {
+            day,
+            line: point.row as usize + 1,
+            column: point.col as usize + 1,
+            text: String::new(),
+            message: format!("map has more than one {}", name),
+        }),
+    }
+}
+
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    match name {
+        "u8" | "u16" | "u32" | "u64" | "usize" => "unsigned number",
+        "i8" | "i16" | "i32" | "i64" | "isize" => "number",
+        _ => name.rsplit("::").next().unwrap_or(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_columns() {
+        let line = Line { day: 1, number: 3, text: "12   x4" };
+        let error = line.parse_fields::<i32>().unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 6, "x4"));
+        assert_eq!(error.to_string(), "day 1, line 3, column 6: invalid number (found `x4`)");
+    }
+
+    #[test]
+    fn grid_errors() {
+        let map = |c| match c {
+            '#' | '.' => Some(c),
+            _ => None,
+        };
+
+        let error = Grid::parse(6, "###\n#x#\n###", "`#` or `.`", map).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = Grid::parse(6, "###\n#.\n###", "`#` or `.`", map).unwrap_err();
+        assert_eq!(error.message, "expected a row of 3 characters");
+
+        let grid = Grid::parse(6, "###\n#..\n###", "`#` or `.`", map).unwrap();
+        let error = check_border(6, &grid, |c| *c == '#').unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
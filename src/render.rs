@@ -0,0 +1,316 @@
+use crate::grid::{Direction, Grid, Point};
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Pixels per cell side in PPM images.
+pub const PPM_SCALE: usize = 4;
+
+/// Units per cell side in SVG images.
+pub const SVG_SCALE: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const BLACK: Colour = Colour::new(0, 0, 0);
+    pub const WHITE: Colour = Colour::new(255, 255, 255);
+    pub const GREY: Colour = Colour::new(128, 128, 128);
+    pub const DARK_GREY: Colour = Colour::new(48, 48, 48);
+    pub const RED: Colour = Colour::new(220, 50, 47);
+    pub const GREEN: Colour = Colour::new(80, 200, 80);
+    pub const BLUE: Colour = Colour::new(60, 110, 230);
+    pub const YELLOW: Colour = Colour::new(240, 200, 40);
+    pub const ORANGE: Colour = Colour::new(240, 140, 30);
+    pub const CYAN: Colour = Colour::new(40, 200, 220);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Colour { r, g, b }
+    }
+
+    /// Colour as `#rrggbb`.
+    pub fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// How a single cell is drawn: a character in text, a colour everywhere else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub colour: Colour,
+}
+
+impl Cell {
+    pub const fn new(symbol: char, colour: Colour) -> Self {
+        Cell { symbol, colour }
+    }
+}
+
+/// Output format of a picture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Ansi,
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    /// Format given by a file extension: `.ppm`, `.svg`, `.ans` for ANSI, and text otherwise.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("ppm") => Format::Ppm,
+            Some("svg") => Format::Svg,
+            Some("ans") => Format::Ansi,
+            _ => Format::Text,
+        }
+    }
+}
+
+/// Grid drawn as cells, with overlays added on top, ready to be written out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Picture {
+    cells: Grid<Cell>,
+}
+
+impl Picture {
+    /// Picture of a grid, drawing each value as a cell.
+    pub fn new<T>(grid: &Grid<T>, cell: impl Fn(&T) -> Cell) -> Self {
+        Picture { cells: grid.map(cell) }
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Replace the cells at the points, ignoring any that are off the grid.
+    pub fn overlay(&mut self, points: impl IntoIterator<Item=Point>, cell: Cell) -> &mut Self {
+        for point in points {
+            if let Some(old) = self.cells.get_mut(point) {
+                *old = cell;
+            }
+        }
+
+        self
+    }
+
+    /// Recolour the cells at the points, keeping their symbols.
+    pub fn highlight(&mut self, points: impl IntoIterator<Item=Point>, colour: Colour) -> &mut Self {
+        for point in points {
+            if let Some(old) = self.cells.get_mut(point) {
+                old.colour = colour;
+            }
+        }
+
+        self
+    }
+
+    /// Draw a route, each cell an arrow towards the next one.
+    pub fn path(&mut self, points: &[Point], colour: Colour) -> &mut Self {
+        self.highlight(points.iter().copied(), colour);
+        for pair in points.windows(2) {
+            if let (Some(direction), Some(cell)) = (Direction::from_offset(pair[1] - pair[0]),
+                                                    self.cells.get_mut(pair[0])) {
+                cell.symbol = direction.arrow();
+            }
+        }
+
+        self
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for row in self.cells.rows() {
+            text.extend(row.iter().map(|cell| cell.symbol));
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Text in 24-bit terminal colours.
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                // Only change colour when it differs from the previous cell
+                if current != Some(cell.colour) {
+                    let Colour { r, g, b } = cell.colour;
+                    write!(text, "\x1b[38;2;{};{};{}m", r, g, b).unwrap();
+                    current = Some(cell.colour);
+                }
+                text.push(cell.symbol);
+            }
+            text.push_str("\x1b[0m\n");
+        }
+
+        text
+    }
+
+    /// Binary PPM image, each cell a square of `scale` pixels.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let width = self.cells.num_cols() * scale;
+        let height = self.cells.num_rows() * scale;
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+
+        for row in self.cells.rows() {
+            for _ in 0..scale {
+                for cell in row {
+                    for _ in 0..scale {
+                        image.extend([cell.colour.r, cell.colour.g, cell.colour.b]);
+                    }
+                }
+            }
+        }
+
+        image
+    }
+
+    /// SVG image, each cell a square `scale` units wide.
+    pub fn to_svg(&self, scale: usize) -> String {
+        let width = self.cells.num_cols() * scale;
+        let height = self.cells.num_rows() * scale;
+        let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+                               viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+                              width, height, width, height);
+
+        for (point, cell) in self.cells.iter() {
+            writeln!(svg, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                     point.col as usize * scale, point.row as usize * scale, scale, scale, cell.colour.hex()).unwrap();
+        }
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    pub fn render(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Text => self.to_text().into_bytes(),
+            Format::Ansi => self.to_ansi().into_bytes(),
+            Format::Ppm => self.to_ppm(PPM_SCALE),
+            Format::Svg => self.to_svg(SVG_SCALE).into_bytes(),
+        }
+    }
+
+    /// Write to a file, in the format given by its extension.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.render(Format::from_path(path)))
+    }
+}
+
+
+/// Pictures of a simulation, each with the number of its step.
+pub type Frames<'a> = Box<dyn Iterator<Item=(usize, Picture)> + 'a>;
+
+/// Write frames as text one after the other, each under a line with its step.
+pub fn write_flip_book(frames: impl Iterator<Item=(usize, Picture)>,
+                       out: &mut impl Write) -> io::Result<usize> {
+    let mut count = 0;
+    for (step, picture) in frames {
+        writeln!(out, "--- step {} ---", step)?;
+        out.write_all(picture.to_text().as_bytes())?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Write frames as PPM images named by their step, `step-000012.ppm`, in a directory.
+pub fn save_frames(frames: impl Iterator<Item=(usize, Picture)>, dir: &Path) -> io::Result<usize> {
+    fs::create_dir_all(dir)?;
+
+    let mut count = 0;
+    for (step, picture) in frames {
+        fs::write(dir.join(format!("step-{:06}.ppm", step)), picture.to_ppm(PPM_SCALE))?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Write frames to a `.txt` flip-book, or otherwise as images in a directory,
+/// returning how many were written.
+pub fn record(frames: impl Iterator<Item=(usize, Picture)>, path: &Path) -> io::Result<usize> {
+    if path.extension().is_some_and(|ext| ext == "txt") {
+        let mut out = BufWriter::new(File::create(path)?);
+        let count = write_flip_book(frames, &mut out)?;
+        out.flush()?;
+        Ok(count)
+    } else {
+        save_frames(frames, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture() -> Picture {
+        let grid = Grid::from_rows(vec![vec![true, false, false], vec![false, false, true]]).unwrap();
+        Picture::new(&grid, |&wall| if wall {
+            Cell::new('#', Colour::GREY)
+        } else {
+            Cell::new('.', Colour::BLACK)
+        })
+    }
+
+    #[test]
+    fn text_with_overlays() {
+        let mut picture = picture();
+        assert_eq!(picture.to_text(), "#..\n..#\n");
+
+        picture.overlay([Point::new(1, 0), Point::new(5, 5)], Cell::new('O', Colour::RED))
+            .path(&[Point::new(0, 1), Point::new(0, 2), Point::new(1, 2)], Colour::GREEN);
+        assert_eq!(picture.to_text(), "#>v\nO.#\n");
+        assert_eq!(picture.cells()[Point::new(1, 2)], Cell::new('#', Colour::GREEN));
+    }
+
+    #[test]
+    fn ansi_colours() {
+        let text = picture().to_ansi();
+        assert!(text.starts_with("\x1b[38;2;128;128;128m#\x1b[38;2;0;0;0m..\x1b[0m\n"));
+    }
+
+    #[test]
+    fn images() {
+        let ppm = picture().to_ppm(2);
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+        assert_eq!(&ppm[header.len()..header.len() + 3], &[128, 128, 128]);
+
+        let svg = picture().to_svg(10);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<rect").count(), 6);
+        assert!(svg.contains("x=\"20\" y=\"10\" width=\"10\" height=\"10\" fill=\"#808080\""));
+    }
+
+    #[test]
+    fn flip_book() {
+        let frames = (0..3).map(|step| {
+            let mut picture = picture();
+            picture.overlay([Point::new(1, step)], Cell::new('@', Colour::YELLOW));
+            (step as usize, picture)
+        });
+
+        let mut out = Vec::new();
+        assert_eq!(write_flip_book(frames.step_by(2), &mut out).unwrap(), 2);
+        assert_eq!(String::from_utf8(out).unwrap(),
+                   "--- step 0 ---\n#..\n@.#\n--- step 2 ---\n#..\n..@\n");
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Format::from_path(Path::new("map.ppm")), Format::Ppm);
+        assert_eq!(Format::from_path(Path::new("map.svg")), Format::Svg);
+        assert_eq!(Format::from_path(Path::new("map.ans")), Format::Ansi);
+        assert_eq!(Format::from_path(Path::new("map.txt")), Format::Text);
+    }
+}
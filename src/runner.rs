@@ -0,0 +1,347 @@
+use crate::answers::{answers_path, Answers, Verdict};
+use crate::days::*;
+use crate::input::{read_input, InputSource};
+use crate::render;
+use crate::{Answer, Solution};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::env;
+use std::time::{Duration, Instant};
+
+/// Days that have a solver.
+pub const DAYS: [u8; 25] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+];
+
+/// Which parts of a day to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    Both,
+    Part1,
+    Part2,
+}
+
+impl Parts {
+    pub fn includes_part1(&self) -> bool {
+        *self != Parts::Part2
+    }
+
+    pub fn includes_part2(&self) -> bool {
+        *self != Parts::Part1
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// Answers and timings from solving a single day.
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub part1: Option<PartReport>,
+    pub part2: Option<PartReport>,
+}
+
+impl DayReport {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time +
+            self.part1.as_ref().map_or(Duration::ZERO, |p| p.time) +
+            self.part2.as_ref().map_or(Duration::ZERO, |p| p.time)
+    }
+}
+
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+/// Parse the input and solve the selected parts, timing each step.
+pub fn solve<S: Solution>(input: &str, parts: Parts) -> Result<DayReport, Box<dyn Error>> {
+    let (parsed, parse_time) = timed(|| S::parse(input));
+    let parsed = parsed?;
+
+    let part1 = parts.includes_part1().then(|| {
+        let (answer, time) = timed(|| S::part1(&parsed));
+        PartReport { answer, time }
+    });
+    let part2 = parts.includes_part2().then(|| {
+        let (answer, time) = timed(|| S::part2(&parsed));
+        PartReport { answer, time }
+    });
+
+    Ok(DayReport { day: S::DAY, parse_time, part1, part2 })
+}
+
+/// Solve a day chosen at runtime.
+pub fn solve_day(day: u8, input: &str, parts: Parts) -> Result<DayReport, Box<dyn Error>> {
+    match day {
+        1 => solve::<day01::Day01>(input, parts),
+        2 => solve::<day02::Day02>(input, parts),
+        3 => solve::<day03::Day03>(input, parts),
+        4 => solve::<day04::Day04>(input, parts),
+        5 => solve::<day05::Day05>(input, parts),
+        6 => solve::<day06::Day06>(input, parts),
+        7 => solve::<day07::Day07>(input, parts),
+        8 => solve::<day08::Day08>(input, parts),
+        9 => solve::<day09::Day09>(input, parts),
+        10 => solve::<day10::Day10>(input, parts),
+        11 => solve::<day11::Day11>(input, parts),
+        12 => solve::<day12::Day12>(input, parts),
+        13 => solve::<day13::Day13>(input, parts),
+        14 => solve::<day14::Day14>(input, parts),
+        15 => solve::<day15::Day15>(input, parts),
+        16 => solve::<day16::Day16>(input, parts),
+        17 => solve::<day17::Day17>(input, parts),
+        18 => solve::<day18::Day18>(input, parts),
+        19 => solve::<day19::Day19>(input, parts),
+        20 => solve::<day20::Day20>(input, parts),
+        21 => solve::<day21::Day21>(input, parts),
+        22 => solve::<day22::Day22>(input, parts),
+        23 => solve::<day23::Day23>(input, parts),
+        24 => solve::<day24::Day24>(input, parts),
+        25 => solve::<day25::Day25>(input, parts),
+        _ => Err(format!("No solver for day {}", day).into()),
+    }
+}
+
+
+/// Command-line arguments of the day binaries.
+#[derive(Debug, Clone)]
+pub struct DayArgs {
+    pub input: InputSource,
+    /// Check the answers against the known answers file.
+    pub check: bool,
+    /// Add new answers to the known answers file (implies `check`).
+    pub record: bool,
+    pub answers: PathBuf,
+    /// Where to write a picture of the puzzle, `-` for the terminal.
+    pub render: Option<PathBuf>,
+    /// Where to record frames of the simulation: a `.txt` flip-book or a directory of images.
+    pub frames: Option<PathBuf>,
+    /// Record every this many steps.
+    pub every: usize,
+    /// Positional arguments after the input, for binaries that take more.
+    pub extra: Vec<String>,
+}
+
+pub const DAY_USAGE: &str = "\
+Usage: dayNN [--check] [--record] [--answers FILE] [--render FILE] [--frames PATH [--every N]] [INPUT]
+
+Solves both parts from INPUT (default: the day's input in the data root, `-` for stdin).
+
+Options:
+  -c, --check          Compare the answers with the known answers: PASS, FAIL or NEW
+  -r, --record         As --check, and save NEW answers to the known answers
+  -a, --answers FILE   Known answers file (default: answers.toml in the data root)
+      --render FILE    Draw the puzzle as .txt, .ans (colour text), .ppm or .svg, or `-` for the terminal
+      --frames PATH    Record the simulation steps as a .txt flip-book, or PPM images in a directory
+      --every N        Only record every Nth step (default: 1)";
+
+impl DayArgs {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut input = None;
+        let mut check = false;
+        let mut record = false;
+        let mut answers = answers_path();
+        let mut render = None;
+        let mut frames = None;
+        let mut every = 1;
+        let mut extra = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-c" | "--check" => check = true,
+                "-r" | "--record" => record = true,
+                "-a" | "--answers" => {
+                    answers = args.next().map(PathBuf::from)
+                        .ok_or("--answers needs a file")?;
+                }
+                "--render" => {
+                    render = Some(args.next().map(PathBuf::from)
+                        .ok_or("--render needs a file")?);
+                }
+                "--frames" => {
+                    frames = Some(args.next().map(PathBuf::from)
+                        .ok_or("--frames needs a path")?);
+                }
+                "--every" => {
+                    every = args.next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|&n| n > 0)
+                        .ok_or("--every needs a positive number of steps")?;
+                }
+                "-h" | "--help" => return Err(String::new()),
+                _ if input.is_none() => input = Some(InputSource::from_arg(Some(arg))),
+                _ => extra.push(arg.clone()),
+            }
+        }
+
+        Ok(DayArgs {
+            input: input.unwrap_or(InputSource::Default),
+            check: check || record,
+            record,
+            answers,
+            render,
+            frames,
+            every,
+            extra,
+        })
+    }
+
+    /// Arguments of the running binary, printing the usage and exiting if they are invalid.
+    pub fn from_env() -> Self {
+        let args = env::args().skip(1).collect::<Vec<_>>();
+        DayArgs::from_args(&args, DAY_USAGE)
+    }
+
+    /// Parsed arguments, printing `usage` and exiting if they are invalid.
+    pub fn from_args(args: &[String], usage: &str) -> Self {
+        match DayArgs::parse(args) {
+            Ok(args) => args,
+            Err(message) => {
+                if !message.is_empty() {
+                    eprintln!("{}\n", message);
+                }
+                eprintln!("{}", usage);
+                process::exit(2);
+            }
+        }
+    }
+}
+
+
+/// Solve and print the answers, returning whether they all passed the check.
+fn run_day<S: Solution>(args: &DayArgs, file_str: &str) -> Result<bool, Box<dyn Error>> {
+    let report = solve::<S>(file_str, Parts::Both)?;
+
+    if let Some(path) = &args.render {
+        render::<S>(file_str, path)?;
+    }
+    if let Some(path) = &args.frames {
+        record_frames::<S>(file_str, path, args.every)?;
+    }
+
+    if !args.check {
+        for (part, report) in [(1, report.part1), (2, report.part2)] {
+            if let Some(report) = report {
+                println!("Part {}: {}", part, report.answer);
+            }
+        }
+        return Ok(true);
+    }
+
+    let mut answers = Answers::load(&args.answers)?.unwrap_or_default();
+    let mut passed = true;
+    let mut recorded = false;
+    for (part, report) in [(1, report.part1), (2, report.part2)] {
+        let Some(report) = report else {
+            continue;
+        };
+
+        match answers.check(S::DAY, part, &report.answer) {
+            Some(Verdict::New) if args.record => {
+                answers.insert(S::DAY, part, &report.answer);
+                recorded = true;
+                println!("Part {}: {}  NEW (recorded)", part, report.answer);
+            }
+            Some(verdict) => {
+                passed &= !matches!(verdict, Verdict::Fail { .. });
+                println!("Part {}: {}  {}", part, report.answer, verdict);
+            }
+            None => println!("Part {}: {}", part, report.answer),
+        }
+    }
+
+    if recorded {
+        answers.save(&args.answers)
+            .map_err(|e| format!("cannot save answers to {}: {}", args.answers.display(), e))?;
+    }
+
+    Ok(passed)
+}
+
+/// Draw the day's picture to a file, or in colour to the terminal for `-`.
+fn render<S: Solution>(input: &str, path: &Path) -> Result<(), Box<dyn Error>> {
+    let picture = S::render(&S::parse(input)?)
+        .ok_or_else(|| format!("day {} has nothing to render", S::DAY))?;
+
+    if path == Path::new("-") {
+        print!("{}", picture.to_ansi());
+    } else {
+        picture.save(path)
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        println!("Rendered {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Record frames of the day's simulation to a flip-book or a directory of images.
+fn record_frames<S: Solution>(input: &str, path: &Path, every: usize) -> Result<(), Box<dyn Error>> {
+    let input = S::parse(input)?;
+    let frames = S::frames(&input, every)
+        .ok_or_else(|| format!("day {} has no simulation to record", S::DAY))?;
+
+    let count = render::record(frames, path)
+        .map_err(|e| format!("cannot write frames to {}: {}", path.display(), e))?;
+    println!("Recorded {} frames to {}", count, path.display());
+
+    Ok(())
+}
+
+/// Run a day binary with already parsed arguments.
+pub fn run_with<S: Solution>(args: &DayArgs) -> ExitCode {
+    match read_input(S::DAY, &args.input) {
+        Ok(file_str) => run_on::<S>(args, &file_str),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run a day binary on input it has already read, such as stdin that cannot be read again.
+pub fn run_on<S: Solution>(args: &DayArgs, file_str: &str) -> ExitCode {
+    match run_day::<S>(args, file_str) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Entry point of the day binaries: solve both parts from the input and print
+/// the answers, optionally checking them against the known answers.
+pub fn run<S: Solution>() -> ExitCode {
+    let args = DayArgs::from_env();
+    if let Some(arg) = args.extra.first() {
+        eprintln!("Unexpected argument: {}\n\n{}", arg, DAY_USAGE);
+        return ExitCode::from(2);
+    }
+
+    run_with::<S>(&args)
+}
+
+/// Human readable duration, scaled to a sensible unit.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
@@ -0,0 +1,214 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// State waiting in the heap, ordered by its estimated total cost.
+#[derive(Debug, Clone)]
+struct CostState<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for CostState<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority) // Reverse order for min-heap
+    }
+}
+
+impl<S, C: Ord> PartialOrd for CostState<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for CostState<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for CostState<S, C> {}
+
+/// Outcome of a search: the cheapest goals, and every optimal way of reaching them.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    goals: Vec<S>,
+    costs: HashMap<S, C>,
+    previous: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> SearchResult<S, C> {
+    /// Cost of the cheapest goal, `None` if no goal can be reached.
+    pub fn cost(&self) -> Option<C> {
+        self.goals.first().map(|goal| self.costs[goal])
+    }
+
+    /// Goal states reached at the lowest cost.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Lowest known cost of a state that was reached during the search.
+    pub fn cost_of(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// States leading to `state` on its optimal paths.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.previous.get(state).map_or(&[], |states| states)
+    }
+
+    /// One optimal path, from a start to a goal inclusive.
+    pub fn path(&self) -> Option<Vec<S>> {
+        let mut path = vec![self.goals.first()?.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every state on any optimal path to any of the goals.
+    pub fn optimal_states(&self) -> HashSet<S> {
+        let mut visited = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(current) = stack.pop() {
+            if visited.insert(current.clone()) {
+                stack.extend(self.predecessors(&current).iter().cloned());
+            }
+        }
+
+        visited
+    }
+}
+
+/// Dijkstra's search from the start states until the cheapest goals are found.
+///
+/// `neighbours` gives the states reachable from a state with the cost of each step.
+pub fn dijkstra<S, C, N, I, G>(starts: impl IntoIterator<Item=S>,
+                               neighbours: N,
+                               is_goal: G) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output=C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item=(S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search from the start states until the cheapest goals are found.
+///
+/// The heuristic must never overestimate the remaining cost, and must be
+/// consistent for the predecessors to cover every optimal path.
+pub fn astar<S, C, N, I, H, G>(starts: impl IntoIterator<Item=S>,
+                               mut neighbours: N,
+                               mut heuristic: H,
+                               mut is_goal: G) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output=C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item=(S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut heap = BinaryHeap::new();
+    let mut result = SearchResult {
+        goals: Vec::new(),
+        costs: HashMap::new(),
+        previous: HashMap::new(),
+    };
+
+    for start in starts {
+        result.costs.insert(start.clone(), C::default());
+        heap.push(CostState { priority: heuristic(&start), cost: C::default(), state: start });
+    }
+
+    let mut best = None;
+    while let Some(CostState { priority, cost, state }) = heap.pop() {
+        // Everything left is more expensive than the goals found
+        if best.is_some_and(|best| priority > best) {
+            break;
+        }
+
+        // Stale entry, the state was reached more cheaply since
+        if cost > result.costs[&state] {
+            continue;
+        }
+
+        if is_goal(&state) {
+            best = Some(cost);
+            result.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            match result.costs.get(&next) {
+                Some(&known) if next_cost > known => {}
+                Some(&known) if next_cost == known => {
+                    result.previous.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    result.costs.insert(next.clone(), next_cost);
+                    result.previous.insert(next.clone(), vec![state.clone()]);
+                    heap.push(CostState { priority: next_cost + heuristic(&next), cost: next_cost, state: next });
+                }
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps of one or two along a line, costing 1 and 3.
+    fn line_steps(&n: &u32) -> Vec<(u32, u32)> {
+        vec![(n + 1, 1), (n + 2, 3)]
+    }
+
+    #[test]
+    fn cheapest_path() {
+        let result = dijkstra([0], line_steps, |&n| n == 4);
+        assert_eq!(result.cost(), Some(4));
+        assert_eq!(result.path(), Some(vec![0, 1, 2, 3, 4]));
+
+        let result = astar([0], line_steps, |&n| 4u32.saturating_sub(n), |&n| n == 4);
+        assert_eq!(result.cost(), Some(4));
+        assert_eq!(result.cost_of(&2), Some(2));
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let result = dijkstra([0], |&n: &u32| (n < 5).then_some((n + 1, 1)), |&n| n == 10);
+        assert_eq!(result.cost(), None);
+        assert_eq!(result.path(), None);
+        assert!(result.optimal_states().is_empty());
+    }
+
+    #[test]
+    fn all_optimal_paths() {
+        // Diamond 0 -> {1, 2} -> 3 with equal costs, and a dearer detour via 4
+        let edges = HashMap::from([
+            (0, vec![(1, 1), (2, 1), (4, 1)]),
+            (1, vec![(3, 1)]),
+            (2, vec![(3, 1)]),
+            (4, vec![(3, 5)]),
+        ]);
+        let result = dijkstra([0], |n| edges.get(n).cloned().unwrap_or_default(), |&n| n == 3);
+
+        assert_eq!(result.cost(), Some(2));
+        let mut previous = result.predecessors(&3).to_vec();
+        previous.sort();
+        assert_eq!(previous, vec![1, 2]);
+        assert_eq!(result.optimal_states(), HashSet::from([0, 1, 2, 3]));
+    }
+}
@@ -0,0 +1,26 @@
+use crate::parse::ParseError;
+use crate::render::{Frames, Picture};
+use crate::Answer;
+
+/// A single day's puzzle: parse the input once, then solve each part from it.
+pub trait Solution {
+    /// Day of the advent calendar (1 to 25).
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Picture of the puzzle for debugging, for days that have one.
+    fn render(_input: &Self::Input) -> Option<Picture> {
+        None
+    }
+
+    /// Pictures of every `every`th step of a simulation, for days that have one.
+    fn frames(_input: &Self::Input, _every: usize) -> Option<Frames<'_>> {
+        None
+    }
+}
+
@@ -0,0 +1,38 @@
+use aoc24::answers::{answers_path, Answers};
+use aoc24::input::{input_path, read_file};
+use aoc24::runner::{solve_day, Parts, DAYS};
+
+/// Solve every day with a known answer from the real puzzle inputs.
+/// Skipped unless there is an `answers.toml` in the data root.
+#[test]
+fn real_inputs_give_known_answers() {
+    let path = answers_path();
+    let Some(answers) = Answers::load(&path).unwrap() else {
+        eprintln!("No {}, skipping", path.display());
+        return;
+    };
+
+    let mut failures = Vec::new();
+    for day in DAYS {
+        // Only solve the parts with a known answer
+        let parts = match (answers.get(day, 1), answers.get(day, 2)) {
+            (Some(_), Some(_)) => Parts::Both,
+            (Some(_), None) => Parts::Part1,
+            (None, Some(_)) => Parts::Part2,
+            (None, None) => continue,
+        };
+
+        let input = read_file(day, &input_path(day)).unwrap();
+        let report = solve_day(day, &input, parts).unwrap();
+        for (part, report) in [(1, report.part1), (2, report.part2)] {
+            if let (Some(expected), Some(report)) = (answers.get(day, part), report) {
+                let answer = report.answer.to_string();
+                if answer != expected {
+                    failures.push(format!("day {} part {}: expected {}, got {}", day, part, expected, answer));
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}